@@ -2,14 +2,33 @@
 PRAGMA foreign_keys = OFF;
 
 -- Drop tables in an order that prevents dependency conflicts
+DROP TABLE IF EXISTS final_team_standings;
+DROP TABLE IF EXISTS final_driver_standings;
+DROP TABLE IF EXISTS news_items;
+DROP TABLE IF EXISTS driver_rating_history;
+DROP TABLE IF EXISTS practice_plans;
+DROP TABLE IF EXISTS practice_results;
+DROP TABLE IF EXISTS power_unit_component_races;
+DROP TABLE IF EXISTS power_unit_components;
+DROP TABLE IF EXISTS penalties;
+DROP TABLE IF EXISTS race_strategy_stints;
+DROP TABLE IF EXISTS race_strategies;
+DROP TABLE IF EXISTS race_decisions;
+DROP TABLE IF EXISTS safety_car_periods;
+DROP TABLE IF EXISTS race_weather;
+DROP TABLE IF EXISTS stints;
 DROP TABLE IF EXISTS laps;
+DROP TABLE IF EXISTS qualifying_results;
 DROP TABLE IF EXISTS race_driver_results;
 DROP TABLE IF EXISTS race_results;
 DROP TABLE IF EXISTS season_schedules;
+DROP TABLE IF EXISTS points_system_placements;
+DROP TABLE IF EXISTS points_systems;
 DROP TABLE IF EXISTS seasons;
 DROP TABLE IF EXISTS driver_contracts;
 DROP TABLE IF EXISTS drivers;
 DROP TABLE IF EXISTS teams;
+DROP TABLE IF EXISTS power_units;
 DROP TABLE IF EXISTS team_bases;
 DROP TABLE IF EXISTS circuits;
 DROP TABLE IF EXISTS game_config;
@@ -29,7 +48,14 @@ CREATE TABLE drivers (
   id INTEGER PRIMARY KEY,
   first_name TEXT NOT NULL,
   last_name TEXT NOT NULL,
-  rating INTEGER NOT NULL,
+  pace INTEGER NOT NULL,  -- Raw speed over a single lap (0-100)
+  racecraft INTEGER NOT NULL,  -- Overtaking and defending wheel-to-wheel (0-100)
+  consistency INTEGER NOT NULL,  -- How little the lap times vary (0-100)
+  tyre_management INTEGER NOT NULL,  -- How gently the driver wears the tyres (0-100)
+  wet_skill INTEGER NOT NULL,  -- Pace and control on a damp or wet track (0-100)
+  starts INTEGER NOT NULL,  -- Time gained or lost off the line (0-100)
+  experience INTEGER NOT NULL,  -- Fewer mistakes and collisions (0-100)
+  potential INTEGER NOT NULL,  -- Highest overall rating the driver can develop to (0-100)
   fk_country_id INTEGER NOT NULL,
   date_of_birth TIMESTAMP NOT NULL,
   racing_number INTEGER NOT NULL,
@@ -46,10 +72,23 @@ CREATE TABLE teams (
   team_chief TEXT NOT NULL,
   chassis TEXT NOT NULL,
   power_unit TEXT NOT NULL,
+  aero INTEGER NOT NULL,  -- Aerodynamic performance (0-100)
+  power INTEGER NOT NULL,  -- Straight-line performance (0-100)
+  mechanical_grip INTEGER NOT NULL,  -- Low-speed cornering and traction (0-100)
+  reliability INTEGER NOT NULL,  -- Chassis reliability (0-100), affects gearbox/hydraulics failures
+  facilities INTEGER NOT NULL,  -- Simulator and driver development facilities (0-100)
+  reputation INTEGER NOT NULL,  -- Standing and history of the team in the paddock (0-100)
   image_team TEXT,
   image_car TEXT
 );
 
+-- Table: power_units (referenced by name from teams.power_unit)
+CREATE TABLE power_units (
+  id INTEGER PRIMARY KEY,
+  name TEXT NOT NULL,
+  reliability INTEGER NOT NULL  -- Power unit reliability (0-100), affects engine failures
+);
+
 -- Table: team_bases (depends on countries and teams)
 CREATE TABLE team_bases (
   id INTEGER PRIMARY KEY,
@@ -68,6 +107,8 @@ CREATE TABLE driver_contracts (
   date_begin TIMESTAMP NOT NULL,
   date_end TIMESTAMP NOT NULL,
   monthly_wage DOUBLE NOT NULL,
+  is_number_one INTEGER NOT NULL DEFAULT 0,  -- 1 if the team favours the driver over the teammate
+  points_bonus DOUBLE NOT NULL DEFAULT 0,  -- Paid per championship point scored
   FOREIGN KEY (fk_driver_id) REFERENCES drivers(id),
   FOREIGN KEY (fk_team_id) REFERENCES teams(id)
 );
@@ -79,7 +120,13 @@ CREATE TABLE circuits (
   fk_country_id INTEGER NOT NULL,
   city TEXT NOT NULL,
   length_km DOUBLE NOT NULL,
-  lap_amount INTEGER NOT NULL,
+  lap_amount INTEGER NOT NULL CHECK (lap_amount > 0),
+  track_type TEXT NOT NULL,  -- Ex: Street, Permanent
+  average_speed_kmh DOUBLE NOT NULL,  -- Average race lap speed
+  overtaking_difficulty INTEGER NOT NULL,  -- 1 (easy) to 10 (near impossible)
+  tyre_wear INTEGER NOT NULL,  -- 1 (gentle) to 10 (severe), 5 is an average track
+  pit_lane_time_loss DOUBLE NOT NULL,  -- Seconds lost driving through the pit lane
+  rain_probability INTEGER NOT NULL,  -- Typical chance of rain on race day, in percent
   image_circuit TEXT,
   FOREIGN KEY (fk_country_id) REFERENCES countries(id)
 );
@@ -88,11 +135,14 @@ CREATE TABLE circuits (
 CREATE TABLE race_driver_results (
   id INTEGER PRIMARY KEY,
   fk_season_schedule_id INTEGER NOT NULL,
+  session TEXT NOT NULL DEFAULT 'Race',  -- Ex: Race, Sprint
   fk_driver_id INTEGER NOT NULL,
   fk_team_id INTEGER NOT NULL,
   placement INTEGER,
   points INTEGER NOT NULL DEFAULT 0,
-  status TEXT NOT NULL,  -- Ex: Finished, DNF
+  status TEXT NOT NULL,  -- Ex: Finished, Classified (retired after 90% of the distance), DNF, NC, DSQ
+  retired_lap INTEGER,  -- Lap the driver retired on, NULL if finished
+  retirement_reason TEXT,  -- Ex: Engine failure, Collision
   FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id),
   FOREIGN KEY (fk_driver_id) REFERENCES drivers(id),
   FOREIGN KEY (fk_team_id) REFERENCES teams(id)
@@ -104,15 +154,231 @@ CREATE TABLE laps (
   fk_race_driver_result_id INTEGER NOT NULL,
   lap_time_ms INTEGER NOT NULL,
   lap_number INTEGER NOT NULL,
+  position INTEGER NOT NULL,  -- Running position at the end of the lap
   FOREIGN KEY (fk_race_driver_result_id) REFERENCES race_driver_results(id)
 );
 
+-- Table: stints (depends on race_driver_results)
+CREATE TABLE stints (
+  id INTEGER PRIMARY KEY,
+  fk_race_driver_result_id INTEGER NOT NULL,
+  stint_number INTEGER NOT NULL,
+  compound TEXT NOT NULL,  -- Ex: Soft, Medium, Hard, Intermediate, Wet
+  start_lap INTEGER NOT NULL,
+  end_lap INTEGER NOT NULL,  -- The driver pits at the end of this lap, unless it is the last stint
+  FOREIGN KEY (fk_race_driver_result_id) REFERENCES race_driver_results(id)
+);
+
+-- Table: race_weather (depends on season_schedules)
+CREATE TABLE race_weather (
+  id INTEGER PRIMARY KEY,
+  fk_season_schedule_id INTEGER NOT NULL,
+  session TEXT NOT NULL DEFAULT 'Race',  -- Ex: Race, Sprint
+  lap_number INTEGER NOT NULL,
+  condition TEXT NOT NULL,  -- Ex: Dry, Damp, Wet
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id)
+);
+
+-- Table: safety_car_periods (depends on season_schedules)
+CREATE TABLE safety_car_periods (
+  id INTEGER PRIMARY KEY,
+  fk_season_schedule_id INTEGER NOT NULL,
+  session TEXT NOT NULL DEFAULT 'Race',  -- Ex: Race, Sprint
+  kind TEXT NOT NULL,  -- Ex: Safety Car, Virtual Safety Car
+  start_lap INTEGER NOT NULL,
+  end_lap INTEGER NOT NULL,
+  reason TEXT NOT NULL,  -- The retirement that caused it, Ex: Collision
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id)
+);
+
+-- Table: practice_results (depends on season_schedules, drivers, teams)
+CREATE TABLE practice_results (
+  id INTEGER PRIMARY KEY,
+  fk_season_schedule_id INTEGER NOT NULL,
+  session TEXT NOT NULL,  -- Ex: FP1, FP2, FP3
+  fk_driver_id INTEGER NOT NULL,
+  fk_team_id INTEGER NOT NULL,
+  position INTEGER NOT NULL,
+  best_lap_ms INTEGER,  -- NULL if the driver did not run
+  laps INTEGER NOT NULL,
+  programme TEXT NOT NULL,  -- Ex: Qualifying Setup, Race Setup, Balanced
+  qualifying_setup REAL NOT NULL,  -- Setup knowledge after the session (0-100)
+  race_setup REAL NOT NULL,
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id),
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id),
+  FOREIGN KEY (fk_team_id) REFERENCES teams(id)
+);
+
+-- Table: practice_plans (depends on season_schedules, drivers)
+CREATE TABLE practice_plans (
+  id INTEGER PRIMARY KEY,
+  fk_season_schedule_id INTEGER NOT NULL,
+  session TEXT NOT NULL,  -- Ex: FP1, FP2, FP3
+  fk_driver_id INTEGER NOT NULL,
+  laps INTEGER NOT NULL,
+  programme TEXT NOT NULL,
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id),
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id)
+);
+
+-- Table: driver_rating_history (depends on drivers, season_schedules)
+CREATE TABLE driver_rating_history (
+  id INTEGER PRIMARY KEY,
+  fk_driver_id INTEGER NOT NULL,
+  fk_season_schedule_id INTEGER NOT NULL,  -- The race after which the driver developed
+  phase TEXT NOT NULL,  -- Ex: Mid-season, Season end
+  rating_before INTEGER NOT NULL,
+  rating_after INTEGER NOT NULL,
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id),
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id)
+);
+
+-- Table: news_items
+CREATE TABLE news_items (
+  id INTEGER PRIMARY KEY,
+  date TEXT NOT NULL,  -- Game date the news broke
+  message TEXT NOT NULL  -- Ex: Lewis Hamilton leaves Ferrari to join Williams for 2026
+);
+
+-- Table: final_driver_standings (depends on seasons, drivers, teams)
+CREATE TABLE final_driver_standings (
+  id INTEGER PRIMARY KEY,
+  fk_season_id INTEGER NOT NULL,
+  fk_driver_id INTEGER NOT NULL,
+  fk_team_id INTEGER NOT NULL,  -- The team the driver last raced for
+  position INTEGER NOT NULL,
+  points INTEGER NOT NULL,
+  wins INTEGER NOT NULL,
+  FOREIGN KEY (fk_season_id) REFERENCES seasons(id),
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id),
+  FOREIGN KEY (fk_team_id) REFERENCES teams(id)
+);
+
+-- Table: final_team_standings (depends on seasons, teams)
+CREATE TABLE final_team_standings (
+  id INTEGER PRIMARY KEY,
+  fk_season_id INTEGER NOT NULL,
+  fk_team_id INTEGER NOT NULL,
+  position INTEGER NOT NULL,
+  points INTEGER NOT NULL,
+  wins INTEGER NOT NULL,
+  FOREIGN KEY (fk_season_id) REFERENCES seasons(id),
+  FOREIGN KEY (fk_team_id) REFERENCES teams(id)
+);
+
+-- Table: power_unit_components (depends on seasons, drivers, season_schedules)
+CREATE TABLE power_unit_components (
+  id INTEGER PRIMARY KEY,
+  fk_season_id INTEGER NOT NULL,
+  fk_driver_id INTEGER NOT NULL,
+  kind TEXT NOT NULL,  -- Ex: ICE, TC, MGU-H, MGU-K, ES, CE, EX
+  number INTEGER NOT NULL,  -- Counts up per driver, kind and season, above the allocation it costs a grid penalty
+  fk_season_schedule_id INTEGER NOT NULL,  -- The race it is first used at
+  is_fitted INTEGER NOT NULL DEFAULT 1,  -- 1 for the component in the car, 0 for the ones in the pool
+  FOREIGN KEY (fk_season_id) REFERENCES seasons(id),
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id),
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id)
+);
+
+-- Table: power_unit_component_races (depends on power_unit_components, season_schedules)
+CREATE TABLE power_unit_component_races (
+  id INTEGER PRIMARY KEY,
+  fk_power_unit_component_id INTEGER NOT NULL,
+  fk_season_schedule_id INTEGER NOT NULL,
+  distance_km REAL NOT NULL,  -- Distance covered over the race weekend
+  failed INTEGER NOT NULL DEFAULT 0,  -- 1 when the component failed at this race
+  FOREIGN KEY (fk_power_unit_component_id) REFERENCES power_unit_components(id),
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id)
+);
+
+-- Table: penalties (depends on season_schedules, drivers)
+CREATE TABLE penalties (
+  id INTEGER PRIMARY KEY,
+  fk_season_schedule_id INTEGER NOT NULL,
+  session TEXT NOT NULL DEFAULT 'Race',  -- Ex: Race, Sprint
+  fk_driver_id INTEGER NOT NULL,
+  kind TEXT NOT NULL,  -- Ex: Time Penalty, Drive Through, Grid Penalty, Disqualification
+  value INTEGER NOT NULL DEFAULT 0,  -- Seconds for a time penalty, places for a grid penalty
+  lap INTEGER,  -- NULL for penalties given after the race
+  reason TEXT NOT NULL,  -- Ex: Track limits
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id),
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id)
+);
+
+-- Table: race_decisions (depends on season_schedules, drivers)
+CREATE TABLE race_decisions (
+  id INTEGER PRIMARY KEY,
+  fk_season_schedule_id INTEGER NOT NULL,
+  fk_driver_id INTEGER NOT NULL,
+  lap_number INTEGER NOT NULL,  -- The lap the order is carried out on
+  decision TEXT NOT NULL,  -- Ex: Pit Stop, Push, Normal, Conserve, Let Teammate Pass
+  compound TEXT,  -- Tyres fitted at an ordered pit stop, NULL for other orders
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id),
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id)
+);
+
+-- Table: race_strategies (depends on season_schedules, drivers)
+CREATE TABLE race_strategies (
+  id INTEGER PRIMARY KEY,
+  fk_season_schedule_id INTEGER NOT NULL,
+  fk_driver_id INTEGER NOT NULL,
+  fuel_mode TEXT NOT NULL DEFAULT 'Standard',  -- Ex: Lean, Standard, Rich
+  engine_mode TEXT NOT NULL DEFAULT 'Standard',  -- Ex: Conservative, Standard, Aggressive
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id),
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id)
+);
+
+-- Table: race_strategy_stints (depends on race_strategies)
+CREATE TABLE race_strategy_stints (
+  id INTEGER PRIMARY KEY,
+  fk_race_strategy_id INTEGER NOT NULL,
+  stint_number INTEGER NOT NULL,
+  compound TEXT NOT NULL,  -- Ex: Soft, Medium, Hard
+  end_lap INTEGER NOT NULL,  -- The planned stop lap, the race distance for the last stint
+  FOREIGN KEY (fk_race_strategy_id) REFERENCES race_strategies(id)
+);
+
+-- Table: qualifying_results (depends on season_schedules, drivers, teams)
+CREATE TABLE qualifying_results (
+  id INTEGER PRIMARY KEY,
+  fk_season_schedule_id INTEGER NOT NULL,
+  session TEXT NOT NULL DEFAULT 'Race',  -- Ex: Race, Sprint
+  fk_driver_id INTEGER NOT NULL,
+  fk_team_id INTEGER NOT NULL,
+  grid_position INTEGER NOT NULL,
+  q1_time_ms INTEGER NOT NULL,
+  q2_time_ms INTEGER,  -- NULL if knocked out in Q1
+  q3_time_ms INTEGER,  -- NULL if knocked out in Q1 or Q2
+  FOREIGN KEY (fk_season_schedule_id) REFERENCES season_schedules(id),
+  FOREIGN KEY (fk_driver_id) REFERENCES drivers(id),
+  FOREIGN KEY (fk_team_id) REFERENCES teams(id)
+);
+
 -- Table: seasons
 CREATE TABLE seasons (
   id INTEGER PRIMARY KEY,
   year INTEGER NOT NULL
 );
 
+-- Table: points_systems (depends on seasons)
+CREATE TABLE points_systems (
+  id INTEGER PRIMARY KEY,
+  fk_season_id INTEGER NOT NULL,
+  session TEXT NOT NULL,  -- Ex: Race, Sprint
+  fastest_lap_points INTEGER NOT NULL DEFAULT 0,
+  fastest_lap_max_placement INTEGER,  -- The fastest lap only scores when finishing this high, NULL if always
+  FOREIGN KEY (fk_season_id) REFERENCES seasons(id)
+);
+
+-- Table: points_system_placements (depends on points_systems)
+CREATE TABLE points_system_placements (
+  id INTEGER PRIMARY KEY,
+  fk_points_system_id INTEGER NOT NULL,
+  placement INTEGER NOT NULL,  -- Placements without a row score no points, Ex: 1-6 for 10-6-4-3-2-1
+  points INTEGER NOT NULL,
+  FOREIGN KEY (fk_points_system_id) REFERENCES points_systems(id)
+);
+
 -- Table: season_schedules (depends on seasons, circuits, race_results)
 CREATE TABLE season_schedules (
   id INTEGER PRIMARY KEY,
@@ -121,6 +387,10 @@ CREATE TABLE season_schedules (
   date TIMESTAMP NOT NULL,
   status TEXT NOT NULL,
   grand_prix_name TEXT NOT NULL,
+  is_sprint INTEGER NOT NULL DEFAULT 0,  -- 1 if the weekend has a sprint qualifying and sprint race
+  seed INTEGER,  -- RNG seed for the race simulation, set when the race is first run
+  rain_chance INTEGER,  -- Forecast chance of rain in percent, set when the forecast is first made
+  is_final INTEGER NOT NULL DEFAULT 0,  -- 1 once the result can no longer change and the drivers and market have moved on from it
   FOREIGN KEY (fk_season_id) REFERENCES seasons(id),
   FOREIGN KEY (fk_circuit_id) REFERENCES circuits(id)
 );
@@ -31,40 +31,53 @@ INSERT INTO countries (id, name, image_country) VALUES
 (26, 'United States', 'usa');
 
 -- Populate the drivers table with driver details
-INSERT INTO drivers (id, first_name, last_name, rating, fk_country_id, date_of_birth, racing_number, image_driver) VALUES
-(1, 'Max', 'Verstappen', 99, 16, '1997-09-30', 1, 'verstappen'),
-(2, 'Lando', 'Norris', 97, 25, '1999-11-13', 4, 'norris'),
-(3, 'Gabriel', 'Bortoleto', 70, 6, '2004-10-14', 5, 'bortoleto'),
-(4, 'Isack', 'Hadjar', 71, 9, '2004-09-28', 6, 'hadjar'),
-(5, 'Jack', 'Doohan', 70, 1, '2003-01-20', 7, 'doohan'),
-(6, 'Pierre', 'Gasly', 72, 9, '1996-02-07', 10, 'gasly'),
-(7, 'Andrea Kimi', 'Antonelli', 77, 12, '2006-08-25', 12, 'antonelli'),
-(8, 'Fernando', 'Alonso', 73, 21, '1981-07-29', 14, 'alonso'),
-(9, 'Charles', 'Leclerc', 82, 15, '1997-10-16', 16, 'leclerc'),
-(10, 'Lance', 'Stroll', 74, 7, '1998-10-29', 18, 'stroll'),
-(11, 'Yuki', 'Tsunoda', 72, 13, '2000-05-11', 22, 'tsunoda'),
-(12, 'Alexander', 'Albon', 75, 23, '1996-03-23', 23, 'albon'),
-(13, 'Nico', 'Hulkenberg', 72, 10, '1987-08-19', 27, 'hulkenberg'),
-(14, 'Liam', 'Lawson', 70, 17, '2002-02-11', 30, 'lawson'),
-(15, 'Esteban', 'Ocon', 76, 9, '1996-09-17', 31, 'ocon'),
-(16, 'Lewis', 'Hamilton', 79, 25, '1985-01-07', 44, 'hamilton'),
-(17, 'Carlos', 'Sainz Jr.', 72, 21, '1994-09-01', 55, 'sainz'),
-(18, 'George', 'Russell', 88, 25, '1998-02-15', 63, 'russell'),
-(19, 'Oscar', 'Piastri', 93, 1, '2001-04-06', 81, 'piastri'),
-(20, 'Oliver', 'Bearman', 73, 25, '2005-05-08', 87, 'bearman');
+INSERT INTO drivers (id, first_name, last_name, pace, racecraft, consistency, tyre_management, wet_skill, starts, experience, potential, fk_country_id, date_of_birth, racing_number, image_driver) VALUES
+(1, 'Max', 'Verstappen', 99, 98, 96, 95, 98, 92, 90, 98, 16, '1997-09-30', 1, 'verstappen'),
+(2, 'Lando', 'Norris', 98, 94, 95, 96, 90, 90, 85, 96, 25, '1999-11-13', 4, 'norris'),
+(3, 'Gabriel', 'Bortoleto', 71, 70, 69, 70, 72, 72, 62, 86, 6, '2004-10-14', 5, 'bortoleto'),
+(4, 'Isack', 'Hadjar', 73, 71, 68, 70, 74, 74, 62, 85, 9, '2004-09-28', 6, 'hadjar'),
+(5, 'Jack', 'Doohan', 72, 68, 66, 70, 68, 72, 62, 80, 1, '2003-01-20', 7, 'doohan'),
+(6, 'Pierre', 'Gasly', 71, 73, 72, 72, 80, 74, 78, 76, 9, '1996-02-07', 10, 'gasly'),
+(7, 'Andrea Kimi', 'Antonelli', 80, 76, 72, 75, 78, 78, 65, 93, 12, '2006-08-25', 12, 'antonelli'),
+(8, 'Fernando', 'Alonso', 70, 82, 74, 76, 92, 84, 99, 80, 21, '1981-07-29', 14, 'alonso'),
+(9, 'Charles', 'Leclerc', 86, 80, 78, 79, 82, 80, 80, 88, 15, '1997-10-16', 16, 'leclerc'),
+(10, 'Lance', 'Stroll', 72, 72, 73, 74, 80, 85, 80, 76, 7, '1998-10-29', 18, 'stroll'),
+(11, 'Yuki', 'Tsunoda', 74, 71, 68, 70, 76, 72, 74, 78, 13, '2000-05-11', 22, 'tsunoda'),
+(12, 'Alexander', 'Albon', 74, 75, 77, 78, 81, 72, 78, 80, 23, '1996-03-23', 23, 'albon'),
+(13, 'Nico', 'Hulkenberg', 70, 70, 73, 72, 83, 72, 90, 75, 10, '1987-08-19', 27, 'hulkenberg'),
+(14, 'Liam', 'Lawson', 71, 72, 67, 68, 75, 70, 66, 82, 17, '2002-02-11', 30, 'lawson'),
+(15, 'Esteban', 'Ocon', 74, 78, 76, 76, 84, 76, 84, 79, 9, '1996-09-17', 31, 'ocon'),
+(16, 'Lewis', 'Hamilton', 77, 82, 76, 84, 95, 78, 99, 84, 25, '1985-01-07', 44, 'hamilton'),
+(17, 'Carlos', 'Sainz Jr.', 69, 73, 73, 75, 82, 70, 86, 77, 21, '1994-09-01', 55, 'sainz'),
+(18, 'George', 'Russell', 90, 86, 88, 86, 86, 86, 85, 91, 25, '1998-02-15', 63, 'russell'),
+(19, 'Oscar', 'Piastri', 95, 92, 94, 94, 85, 88, 80, 96, 1, '2001-04-06', 81, 'piastri'),
+(20, 'Oliver', 'Bearman', 75, 72, 71, 71, 72, 74, 64, 88, 25, '2005-05-08', 87, 'bearman'),
+-- Reserve drivers without a race seat, free agents on the driver market
+(21, 'Guanyu', 'Zhou', 70, 70, 74, 73, 72, 72, 76, 74, 8, '1999-05-30', 24, 'zhou'),
+(22, 'Mick', 'Schumacher', 70, 68, 68, 70, 74, 70, 70, 76, 10, '1999-03-22', 47, 'schumacher'),
+(23, 'Felipe', 'Drugovich', 71, 70, 70, 71, 70, 70, 62, 78, 6, '2000-05-23', 34, 'drugovich'),
+(24, 'Theo', 'Pourchaire', 72, 69, 68, 69, 70, 71, 58, 82, 9, '2003-08-20', 26, 'pourchaire'),
+(25, 'Logan', 'Sargeant', 66, 64, 62, 66, 64, 66, 62, 70, 26, '2000-12-31', 2, 'sargeant');
 
 -- Populate the teams table with team details
-INSERT INTO teams (id, short_name, full_name, first_entry, team_chief, chassis, power_unit, image_team, image_car) VALUES
-(1, 'Alpine', 'BWT Alpine Formula One Team', 2021, 'Oliver Oakes', 'A525', 'Renault', 'alpine', 'alpine'),
-(2, 'Aston Martin', 'Aston Martin Aramco Formula One Team', 2021, 'Andy Cowell', 'AMR25', 'Mercedes', 'aston_martin', 'aston_martin'),
-(3, 'Ferrari', 'Scuderia Ferrari HP', 1950, 'Frédéric Vasseur', 'SF-25', 'Ferrari', 'ferrari', 'ferrari'),
-(4, 'Haas', 'MoneyGram Haas F1 Team', 2016, 'Ayao Komatsu', 'VF-25', 'Ferrari', 'haas', 'haas'),
-(5, 'McLaren', 'McLaren Formula 1 Team', 1966, 'Andrea Stella', 'MCL39', 'Mercedes', 'mclaren', 'mclaren'),
-(6, 'Mercedes', 'Mercedes-AMG PETRONAS Formula One Team', 2010, 'Toto Wolff', 'W16', 'Mercedes', 'mercedes', 'mercedes'),
-(7, 'Racing Bulls', 'Visa Cash App Racing Bulls Formula One Team', 2006, 'Laurent Mekies', 'VCARB 02', 'Honda RBPT', 'racing_bulls', 'racing_bulls'),
-(8, 'Red Bull Racing', 'Oracle Red Bull Racing', 2005, 'Christian Horner', 'RB21', 'Honda RBPT', 'red_bull_racing', 'red_bull_racing'),
-(9, 'Kick Sauber', 'Stake F1 Team Kick Sauber', 1993, 'Jonathan Wheatley', 'C45', 'Ferrari', 'kick_sauber', 'kick_sauber'),
-(10, 'Williams', 'Atlassian Williams Racing', 1978, 'James Vowles', 'FW47', 'Mercedes', 'williams', 'williams');
+INSERT INTO teams (id, short_name, full_name, first_entry, team_chief, chassis, power_unit, aero, power, mechanical_grip, reliability, facilities, reputation, image_team, image_car) VALUES
+(1, 'Alpine', 'BWT Alpine Formula One Team', 2021, 'Oliver Oakes', 'A525', 'Renault', 80, 80, 81, 85, 80, 60, 'alpine', 'alpine'),
+(2, 'Aston Martin', 'Aston Martin Aramco Formula One Team', 2021, 'Andy Cowell', 'AMR25', 'Mercedes', 83, 89, 82, 88, 90, 78, 'aston_martin', 'aston_martin'),
+(3, 'Ferrari', 'Scuderia Ferrari HP', 1950, 'Frédéric Vasseur', 'SF-25', 'Ferrari', 90, 93, 88, 90, 90, 98, 'ferrari', 'ferrari'),
+(4, 'Haas', 'MoneyGram Haas F1 Team', 2016, 'Ayao Komatsu', 'VF-25', 'Ferrari', 82, 86, 81, 86, 72, 55, 'haas', 'haas'),
+(5, 'McLaren', 'McLaren Formula 1 Team', 1966, 'Andrea Stella', 'MCL39', 'Mercedes', 95, 92, 94, 93, 93, 92, 'mclaren', 'mclaren'),
+(6, 'Mercedes', 'Mercedes-AMG PETRONAS Formula One Team', 2010, 'Toto Wolff', 'W16', 'Mercedes', 90, 92, 89, 92, 94, 90, 'mercedes', 'mercedes'),
+(7, 'Racing Bulls', 'Visa Cash App Racing Bulls Formula One Team', 2006, 'Laurent Mekies', 'VCARB 02', 'Honda RBPT', 84, 87, 83, 88, 82, 58, 'racing_bulls', 'racing_bulls'),
+(8, 'Red Bull Racing', 'Oracle Red Bull Racing', 2005, 'Christian Horner', 'RB21', 'Honda RBPT', 91, 91, 87, 91, 92, 90, 'red_bull_racing', 'red_bull_racing'),
+(9, 'Kick Sauber', 'Stake F1 Team Kick Sauber', 1993, 'Jonathan Wheatley', 'C45', 'Ferrari', 79, 84, 80, 84, 76, 62, 'kick_sauber', 'kick_sauber'),
+(10, 'Williams', 'Atlassian Williams Racing', 1978, 'James Vowles', 'FW47', 'Mercedes', 84, 90, 83, 87, 84, 80, 'williams', 'williams');
+
+-- Populate the power_units table with the power unit suppliers
+INSERT INTO power_units (id, name, reliability) VALUES
+(1, 'Ferrari', 90),
+(2, 'Honda RBPT', 91),
+(3, 'Mercedes', 92),
+(4, 'Renault', 86);
 
 -- Populate the team_bases table with team base details
 INSERT INTO team_bases (id, fk_team_id, city, fk_country_id) VALUES
@@ -103,62 +116,87 @@ INSERT INTO driver_contracts (fk_driver_id, fk_team_id, date_begin, date_end, mo
 (20, 4, '2025-01-01', '2025-12-31', 100000.0);  -- Oliver Bearman with Haas
 
 -- Populate the circuits table with circuit details for the first few races
-INSERT INTO circuits (id, name, fk_country_id, city, length_km, lap_amount, image_circuit) VALUES
-(1, 'Albert Park Circuit', 1, 'Melbourne', 5.278, 58, 'albert_park'),
-(2, 'Shanghai International Circuit', 8, 'Shanghai', 5.451, 56, 'shanghai'),
-(3, 'Suzuka International Racing Course', 13, 'Suzuka', 5.807, 53, 'suzuka'),
-(4, 'Bahrain International Circuit', 4, 'Sakhir', 5.412, 57, 'bahrain'),
-(5, 'Jeddah Corniche Circuit', 19, 'Jeddah', 6.174, 50, 'jeddah'),
-(6, 'Miami International Autodrome', 26, 'Miami', 5.412, 57, 'miami'),
-(7, 'Autodromo Enzo e Dino Ferrari', 12, 'Imola', 4.909, 63, 'imola'),
-(8, 'Circuit de Monaco', 15, 'Monaco', 3.337, 78, 'monaco'),
-(9, 'Circuit de Barcelona-Catalunya', 21, 'Barcelona', 4.657, 66, 'barcelona'),
-(10, 'Circuit Gilles Villeneuve', 7, 'Montreal', 4.361, 70, 'gilles_villeneuve'),
-(11, 'Red Bull Ring', 2, 'Spielberg', 4.318, 71, 'red_bull_ring'),
-(12, 'Silverstone Circuit', 25, 'Silverstone', 5.891, 52, 'silverstone'),
-(13, 'Circuit de Spa-Francorchamps', 5, 'Stavelot', 7.004, 44, 'spa'),
-(14, 'Hungaroring', 11, 'Mogyoród', 4.381, 70, 'hungaroring'),
-(15, 'Circuit Zandvoort', 16, 'Zandvoort', 4.259, 72, 'zandvoort'),
-(16, 'Autodromo Nazionale di Monza', 12, 'Monza', 5.793, 53, 'monza'),
-(17, 'Baku City Circuit', 3, 'Baku', 6.003, 51, 'baku'),
-(18, 'Marina Bay Street Circuit', 20, 'Singapore', 4.940, 62, 'singapore'),
-(19, 'Circuit of The Americas', 26, 'Austin', 5.513, 56, 'cota'),
-(20, 'Autódromo Hermanos Rodríguez', 14, 'Mexico City', 4.304, 71, 'mexico_city'),
-(21, 'Autódromo José Carlos Pace', 6, 'São Paulo', 4.309, 71, 'interlagos'),
-(22, 'Las Vegas Street Circuit', 26, 'Las Vegas', 6.201, 50, 'las_vegas'),
-(23, 'Lusail International Circuit', 18, 'Lusail', 5.419, 57, 'lusail'),
-(24, 'Yas Marina Circuit', 24, 'Abu Dhabi', 5.281, 58, 'yas_marina');
+INSERT INTO circuits (id, name, fk_country_id, city, length_km, lap_amount, track_type, average_speed_kmh, overtaking_difficulty, tyre_wear, pit_lane_time_loss, rain_probability, image_circuit) VALUES
+(1, 'Albert Park Circuit', 1, 'Melbourne', 5.278, 58, 'Street', 234.0, 5, 4, 19.0, 20, 'albert_park'),
+(2, 'Shanghai International Circuit', 8, 'Shanghai', 5.451, 56, 'Permanent', 202.0, 4, 7, 23.0, 25, 'shanghai'),
+(3, 'Suzuka International Racing Course', 13, 'Suzuka', 5.807, 53, 'Permanent', 222.0, 7, 7, 22.0, 30, 'suzuka'),
+(4, 'Bahrain International Circuit', 4, 'Sakhir', 5.412, 57, 'Permanent', 203.0, 3, 8, 23.0, 5, 'bahrain'),
+(5, 'Jeddah Corniche Circuit', 19, 'Jeddah', 6.174, 50, 'Street', 244.0, 4, 3, 20.0, 5, 'jeddah'),
+(6, 'Miami International Autodrome', 26, 'Miami', 5.412, 57, 'Street', 214.0, 5, 5, 20.0, 20, 'miami'),
+(7, 'Autodromo Enzo e Dino Ferrari', 12, 'Imola', 4.909, 63, 'Permanent', 224.0, 8, 5, 28.0, 25, 'imola'),
+(8, 'Circuit de Monaco', 15, 'Monaco', 3.337, 78, 'Street', 160.0, 9, 1, 19.0, 15, 'monaco'),
+(9, 'Circuit de Barcelona-Catalunya', 21, 'Barcelona', 4.657, 66, 'Permanent', 218.0, 6, 8, 21.0, 15, 'barcelona'),
+(10, 'Circuit Gilles Villeneuve', 7, 'Montreal', 4.361, 70, 'Street', 209.0, 4, 4, 18.0, 30, 'gilles_villeneuve'),
+(11, 'Red Bull Ring', 2, 'Spielberg', 4.318, 71, 'Permanent', 229.0, 3, 5, 20.0, 30, 'red_bull_ring'),
+(12, 'Silverstone Circuit', 25, 'Silverstone', 5.891, 52, 'Permanent', 236.0, 5, 8, 20.0, 40, 'silverstone'),
+(13, 'Circuit de Spa-Francorchamps', 5, 'Stavelot', 7.004, 44, 'Permanent', 238.0, 3, 6, 18.0, 50, 'spa'),
+(14, 'Hungaroring', 11, 'Mogyoród', 4.381, 70, 'Permanent', 197.0, 8, 6, 20.0, 20, 'hungaroring'),
+(15, 'Circuit Zandvoort', 16, 'Zandvoort', 4.259, 72, 'Permanent', 210.0, 8, 6, 20.0, 35, 'zandvoort'),
+(16, 'Autodromo Nazionale di Monza', 12, 'Monza', 5.793, 53, 'Permanent', 254.0, 3, 4, 24.0, 20, 'monza'),
+(17, 'Baku City Circuit', 3, 'Baku', 6.003, 51, 'Street', 206.0, 3, 3, 20.0, 10, 'baku'),
+(18, 'Marina Bay Street Circuit', 20, 'Singapore', 4.940, 62, 'Street', 187.0, 8, 5, 28.0, 35, 'singapore'),
+(19, 'Circuit of The Americas', 26, 'Austin', 5.513, 56, 'Permanent', 202.0, 4, 7, 21.0, 15, 'cota'),
+(20, 'Autódromo Hermanos Rodríguez', 14, 'Mexico City', 4.304, 71, 'Permanent', 194.0, 5, 3, 22.0, 15, 'mexico_city'),
+(21, 'Autódromo José Carlos Pace', 6, 'São Paulo', 4.309, 71, 'Permanent', 212.0, 3, 5, 21.0, 45, 'interlagos'),
+(22, 'Las Vegas Street Circuit', 26, 'Las Vegas', 6.201, 50, 'Street', 232.0, 3, 3, 21.0, 5, 'las_vegas'),
+(23, 'Lusail International Circuit', 18, 'Lusail', 5.419, 57, 'Permanent', 229.0, 6, 9, 26.0, 5, 'lusail'),
+(24, 'Yas Marina Circuit', 24, 'Abu Dhabi', 5.281, 58, 'Permanent', 216.0, 6, 4, 22.0, 5, 'yas_marina');
 
 -- Populate a season
 INSERT INTO seasons (id, year) VALUES
 (1, 2025);
 
+-- Populate the points systems, 2025 has no fastest lap point
+INSERT INTO points_systems (id, fk_season_id, session, fastest_lap_points, fastest_lap_max_placement) VALUES
+(1, 1, 'Race', 0, NULL),
+(2, 1, 'Sprint', 0, NULL);
+
+INSERT INTO points_system_placements (fk_points_system_id, placement, points) VALUES
+(1, 1, 25),
+(1, 2, 18),
+(1, 3, 15),
+(1, 4, 12),
+(1, 5, 10),
+(1, 6, 8),
+(1, 7, 6),
+(1, 8, 4),
+(1, 9, 2),
+(1, 10, 1),
+(2, 1, 8),
+(2, 2, 7),
+(2, 3, 6),
+(2, 4, 5),
+(2, 5, 4),
+(2, 6, 3),
+(2, 7, 2),
+(2, 8, 1);
+
 -- Populate a season schedule
-INSERT INTO season_schedules (fk_season_id, fk_circuit_id, date, status, grand_prix_name) VALUES
-(1, 1, '2025-03-16', 'Upcoming', 'Australian Grand Prix'),
-(1, 2, '2025-03-23', 'Upcoming', 'Chinese Grand Prix'),
-(1, 3, '2025-04-06', 'Upcoming', 'Japanese Grand Prix'),
-(1, 4, '2025-04-13', 'Upcoming', 'Bahrain Grand Prix'),
-(1, 5, '2025-04-20', 'Upcoming', 'Saudi Arabian Grand Prix'),
-(1, 6, '2025-05-04', 'Upcoming', 'Miami Grand Prix'),
-(1, 7, '2025-05-18', 'Upcoming', 'Emilia Romagna Grand Prix'),
-(1, 8, '2025-05-25', 'Upcoming', 'Monaco Grand Prix'),
-(1, 9, '2025-06-01', 'Upcoming', 'Spanish Grand Prix'),
-(1, 10, '2025-06-15', 'Upcoming', 'Canadian Grand Prix'),
-(1, 11, '2025-06-29', 'Upcoming', 'Austrian Grand Prix'),
-(1, 12, '2025-07-06', 'Upcoming', 'British Grand Prix'),
-(1, 13, '2025-07-27', 'Upcoming', 'Belgian Grand Prix'),
-(1, 14, '2025-08-03', 'Upcoming', 'Hungarian Grand Prix'),
-(1, 15, '2025-08-31', 'Upcoming', 'Dutch Grand Prix'),
-(1, 16, '2025-09-07', 'Upcoming', 'Italian Grand Prix'),
-(1, 17, '2025-09-21', 'Upcoming', 'Azerbaijan Grand Prix'),
-(1, 18, '2025-10-05', 'Upcoming', 'Singapore Grand Prix'),
-(1, 19, '2025-10-19', 'Upcoming', 'United States Grand Prix'),
-(1, 20, '2025-10-26', 'Upcoming', 'Mexico City Grand Prix'),
-(1, 21, '2025-11-09', 'Upcoming', 'São Paulo Grand Prix'),
-(1, 22, '2025-11-22', 'Upcoming', 'Las Vegas Grand Prix'),
-(1, 23, '2025-11-30', 'Upcoming', 'Qatar Grand Prix'),
-(1, 24, '2025-12-07', 'Upcoming', 'Abu Dhabi Grand Prix');
+INSERT INTO season_schedules (fk_season_id, fk_circuit_id, date, status, grand_prix_name, is_sprint) VALUES
+(1, 1, '2025-03-16', 'Upcoming', 'Australian Grand Prix', 0),
+(1, 2, '2025-03-23', 'Upcoming', 'Chinese Grand Prix', 1),
+(1, 3, '2025-04-06', 'Upcoming', 'Japanese Grand Prix', 0),
+(1, 4, '2025-04-13', 'Upcoming', 'Bahrain Grand Prix', 0),
+(1, 5, '2025-04-20', 'Upcoming', 'Saudi Arabian Grand Prix', 0),
+(1, 6, '2025-05-04', 'Upcoming', 'Miami Grand Prix', 1),
+(1, 7, '2025-05-18', 'Upcoming', 'Emilia Romagna Grand Prix', 0),
+(1, 8, '2025-05-25', 'Upcoming', 'Monaco Grand Prix', 0),
+(1, 9, '2025-06-01', 'Upcoming', 'Spanish Grand Prix', 0),
+(1, 10, '2025-06-15', 'Upcoming', 'Canadian Grand Prix', 0),
+(1, 11, '2025-06-29', 'Upcoming', 'Austrian Grand Prix', 0),
+(1, 12, '2025-07-06', 'Upcoming', 'British Grand Prix', 0),
+(1, 13, '2025-07-27', 'Upcoming', 'Belgian Grand Prix', 1),
+(1, 14, '2025-08-03', 'Upcoming', 'Hungarian Grand Prix', 0),
+(1, 15, '2025-08-31', 'Upcoming', 'Dutch Grand Prix', 0),
+(1, 16, '2025-09-07', 'Upcoming', 'Italian Grand Prix', 0),
+(1, 17, '2025-09-21', 'Upcoming', 'Azerbaijan Grand Prix', 0),
+(1, 18, '2025-10-05', 'Upcoming', 'Singapore Grand Prix', 0),
+(1, 19, '2025-10-19', 'Upcoming', 'United States Grand Prix', 1),
+(1, 20, '2025-10-26', 'Upcoming', 'Mexico City Grand Prix', 0),
+(1, 21, '2025-11-09', 'Upcoming', 'São Paulo Grand Prix', 1),
+(1, 22, '2025-11-22', 'Upcoming', 'Las Vegas Grand Prix', 0),
+(1, 23, '2025-11-30', 'Upcoming', 'Qatar Grand Prix', 1),
+(1, 24, '2025-12-07', 'Upcoming', 'Abu Dhabi Grand Prix', 0);
 
 -- Populate the config table with season settings
 INSERT INTO game_config (starting_year, current_date, selected_team) VALUES
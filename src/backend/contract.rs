@@ -0,0 +1,201 @@
+use crate::database::config::get_current_date;
+use crate::database::driver::{get_driver_by_id, get_driver_contracts, insert_driver_contract};
+use crate::database::teams::{get_car_performance, get_team_contract_count, get_team_reputation};
+use crate::model::{Driver, DriverContract};
+use chrono::{Datelike, Duration, NaiveDate};
+
+pub const MAX_CONTRACT_YEARS: u8 = 5;
+pub const SEATS_PER_TEAM: u8 = 2;
+const MARKET_WAGE_BASE: f32 = 50_000.0; // Monthly wage of a driver rated 70
+const MARKET_WAGE_GROWTH: f32 = 0.08; // The wage grows by this share per rating point above that
+const COMPETITIVENESS_WEIGHT: f32 = 0.6; // Against the reputation in how attractive a team is
+const MAX_ASKING_FACTOR: f32 = 1.3; // Share of the market wage asked of the least attractive team
+const MIN_ASKING_FACTOR: f32 = 0.7; // And of the most attractive one
+const STAR_RATING: u8 = 85; // Drivers from this rating on expect number one status
+const NUMBER_ONE_DISCOUNT: f32 = 0.1; // Wage a driver gives up for number one status
+const NO_NUMBER_ONE_PREMIUM: f32 = 0.15; // Extra a star asks without it
+const YEAR_MISMATCH_PREMIUM: f32 = 0.05; // Extra asked per year away from the preferred length
+const MAX_SEASON_POINTS: f32 = 400.0; // Points a driver in the best car can expect over a season
+const BONUS_VALUE_FACTOR: f32 = 0.7; // Bonuses are uncertain, so they count for less than wage
+const COUNTER_OFFER_RATIO: f32 = 0.75; // Offers worth less than this share of the ask are turned down
+const OPENING_OFFER_RATIO: f32 = 0.8; // Share of the ask the talks start from
+const WAGE_ROUNDING: f32 = 10_000.0;
+pub const MAX_NEGOTIATION_ROUNDS: u8 = 3; // Counter-offers a driver makes before ending the talks
+
+// What the team puts on the table
+#[derive(Clone)]
+pub struct ContractOffer {
+    pub monthly_wage: f32,
+    pub years: u8,
+    pub is_number_one: bool,
+    pub points_bonus: f32,
+}
+
+pub enum OfferResponse {
+    Accepted,
+    CounterOffer(ContractOffer),
+    Rejected(String),
+}
+
+// From 0 to 1, how quick the team's car is. The cars in the game are rated from 70 to 100
+fn competitiveness(team_id: u16) -> f32 {
+    let car = get_car_performance(&team_id).unwrap();
+    let car_rating = (car.aero as f32 + car.power as f32 + car.mechanical_grip as f32) / 3.0;
+    ((car_rating - 70.0) / 30.0).clamp(0.0, 1.0)
+}
+
+// From 0 to 1, a quick car matters more to a driver than the team's name
+pub fn team_attractiveness(team_id: u16) -> f32 {
+    let competitiveness = competitiveness(team_id);
+    let reputation = get_team_reputation(&team_id).unwrap_or(0).min(100) as f32 / 100.0;
+    competitiveness * COMPETITIVENESS_WEIGHT + reputation * (1.0 - COMPETITIVENESS_WEIGHT)
+}
+
+// Monthly wage the driver is worth on the market, it grows steeply with the rating
+pub fn market_wage(driver: &Driver) -> f32 {
+    MARKET_WAGE_BASE * (driver.rating().saturating_sub(70) as f32 * MARKET_WAGE_GROWTH).exp()
+}
+
+// Young drivers keep their options open, drivers in their prime want security and veterans go
+// from year to year
+fn preferred_years(driver: &Driver, date: &str) -> u8 {
+    match driver.age_on(date) {
+        0..=24 => 2,
+        25..=33 => 3,
+        _ => 1,
+    }
+}
+
+// Expected monthly value of the bonus, from the points the team's car can score
+fn bonus_value(team_id: u16, points_bonus: f32) -> f32 {
+    let expected_points = competitiveness(team_id) * MAX_SEASON_POINTS;
+    points_bonus * expected_points / 12.0 * BONUS_VALUE_FACTOR
+}
+
+// Monthly value the driver asks from the team for a contract of that length and status
+fn asking_value(driver: &Driver, team_id: u16, years: u8, is_number_one: bool, date: &str) -> f32 {
+    let attractiveness = team_attractiveness(team_id);
+    let mut asking = market_wage(driver)
+        * (MAX_ASKING_FACTOR - attractiveness * (MAX_ASKING_FACTOR - MIN_ASKING_FACTOR));
+    if is_number_one {
+        asking *= 1.0 - NUMBER_ONE_DISCOUNT;
+    } else if driver.rating() >= STAR_RATING {
+        asking *= 1.0 + NO_NUMBER_ONE_PREMIUM;
+    }
+    let mismatch = years.abs_diff(preferred_years(driver, date));
+    asking * (1.0 + mismatch as f32 * YEAR_MISMATCH_PREMIUM)
+}
+
+// A new contract starts once the driver's current one runs out, or on the earliest date for a
+// free agent. It always runs to the end of a season
+fn contract_dates(driver_id: u16, years: u8, earliest: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let start = get_driver_contracts(&driver_id)
+        .iter()
+        .filter_map(|c| NaiveDate::parse_from_str(&c.date_end, "%Y-%m-%d").ok())
+        .filter(|end| *end >= earliest)
+        .max()
+        .map_or(earliest, |end| end + Duration::days(1));
+    let end = NaiveDate::from_ymd_opt(start.year() + years.max(1) as i32 - 1, 12, 31).unwrap();
+    (start, end)
+}
+
+// The driver's answer to the offer in the given round of the talks, counting from 1
+pub fn evaluate_offer(
+    driver_id: u16,
+    team_id: u16,
+    offer: &ContractOffer,
+    round: u8,
+) -> OfferResponse {
+    let driver = get_driver_by_id(&driver_id).unwrap();
+    let today = get_current_date().unwrap();
+    let (start, _) = contract_dates(driver_id, offer.years, today);
+    let start_date = start.to_string();
+    let signed_ahead = get_driver_contracts(&driver_id).iter().any(|c| {
+        NaiveDate::parse_from_str(&c.date_begin, "%Y-%m-%d").is_ok_and(|begin| begin > today)
+    });
+    if signed_ahead {
+        return OfferResponse::Rejected(
+            "The driver has already signed a contract for the future".to_string(),
+        );
+    }
+    if get_team_contract_count(&team_id, &start_date) >= SEATS_PER_TEAM {
+        return OfferResponse::Rejected(format!(
+            "The team has no free seat on {}, when the contract would start",
+            start_date
+        ));
+    }
+
+    let offered = offer.monthly_wage + bonus_value(team_id, offer.points_bonus);
+    let asking = asking_value(
+        &driver,
+        team_id,
+        offer.years,
+        offer.is_number_one,
+        &start_date,
+    );
+    if offered >= asking {
+        OfferResponse::Accepted
+    } else if round >= MAX_NEGOTIATION_ROUNDS {
+        OfferResponse::Rejected("The driver has ended the talks".to_string())
+    } else if offered < asking * COUNTER_OFFER_RATIO {
+        OfferResponse::Rejected("The offer is too far from what the driver is worth".to_string())
+    } else {
+        // the driver comes back with their preferred length and the wage that makes it work
+        let years = preferred_years(&driver, &start_date);
+        let asking = asking_value(&driver, team_id, years, offer.is_number_one, &start_date);
+        OfferResponse::CounterOffer(ContractOffer {
+            monthly_wage: (asking - bonus_value(team_id, offer.points_bonus))
+                .max(0.0)
+                .ceil(),
+            years,
+            ..offer.clone()
+        })
+    }
+}
+
+// Creates the contract the driver accepted, it takes effect at its start date, no sooner than the
+// earliest one
+pub fn sign_contract(driver_id: u16, team_id: u16, offer: &ContractOffer, earliest: NaiveDate) {
+    let (start, end) = contract_dates(driver_id, offer.years, earliest);
+    insert_driver_contract(
+        driver_id,
+        &DriverContract {
+            team_id,
+            date_begin: start.to_string(),
+            date_end: end.to_string(),
+            monthly_wage: offer.monthly_wage,
+            is_number_one: offer.is_number_one,
+            points_bonus: offer.points_bonus,
+        },
+    );
+}
+
+// Where the talks start, a little below what the driver is asking from the team
+pub fn opening_offer(driver_id: u16, team_id: u16) -> ContractOffer {
+    let driver = get_driver_by_id(&driver_id).unwrap();
+    let (start, _) = contract_dates(driver_id, 1, get_current_date().unwrap());
+    let years = preferred_years(&driver, &start.to_string());
+    let asking = asking_value(&driver, team_id, years, false, &start.to_string());
+    ContractOffer {
+        monthly_wage: (asking * OPENING_OFFER_RATIO / WAGE_ROUNDING).ceil() * WAGE_ROUNDING,
+        years,
+        is_number_one: false,
+        points_bonus: 0.0,
+    }
+}
+
+// What an AI team offers, the full ask at the driver's preferred length so the deal goes through.
+// Stars get number one status
+pub fn ai_offer(driver_id: u16, team_id: u16, earliest: NaiveDate) -> ContractOffer {
+    let driver = get_driver_by_id(&driver_id).unwrap();
+    let (start, _) = contract_dates(driver_id, 1, earliest);
+    let years = preferred_years(&driver, &start.to_string());
+    let is_number_one = driver.rating() >= STAR_RATING;
+    let asking = asking_value(&driver, team_id, years, is_number_one, &start.to_string());
+    ContractOffer {
+        monthly_wage: (asking / WAGE_ROUNDING).ceil() * WAGE_ROUNDING,
+        years,
+        is_number_one,
+        points_bonus: 0.0,
+    }
+}
@@ -0,0 +1,126 @@
+use crate::backend::tyre::{Compound, TyreMode};
+use crate::model::{RaceDecision, SafetyCarPeriod};
+
+const DECISION_INTERVAL_LAPS: u8 = 10; // The race pauses for the player's orders this often
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum RaceOrder {
+    PitStop(Compound), // Pit at the end of the lap for these tyres, instead of the next planned stop
+    TyreMode(TyreMode), // Drive in this mode from the lap on
+    LetTeammatePass,   // Let the teammate close behind through on the lap
+}
+
+impl RaceOrder {
+    pub fn name(&self) -> &'static str {
+        match self {
+            RaceOrder::PitStop(_) => "Pit Stop",
+            RaceOrder::TyreMode(mode) => mode.name(),
+            RaceOrder::LetTeammatePass => "Let Teammate Pass",
+        }
+    }
+}
+
+// An order for one driver, carried out on the given lap
+#[derive(Clone, Copy)]
+pub struct DriverOrder {
+    pub driver_id: u16,
+    pub lap: u8,
+    pub order: RaceOrder,
+}
+
+impl DriverOrder {
+    pub fn from_decision(decision: &RaceDecision) -> Option<DriverOrder> {
+        let order = match decision.decision.as_str() {
+            "Pit Stop" => RaceOrder::PitStop(Compound::from_name(decision.compound.as_deref()?)?),
+            "Let Teammate Pass" => RaceOrder::LetTeammatePass,
+            mode => RaceOrder::TyreMode(TyreMode::from_name(mode)?),
+        };
+        Some(DriverOrder {
+            driver_id: decision.driver_id,
+            lap: decision.lap,
+            order,
+        })
+    }
+
+    pub fn to_decision(self) -> RaceDecision {
+        RaceDecision {
+            driver_id: self.driver_id,
+            lap: self.lap,
+            decision: self.order.name().to_string(),
+            compound: match self.order {
+                RaceOrder::PitStop(compound) => Some(compound.name().to_string()),
+                _ => None,
+            },
+        }
+    }
+}
+
+// The race pauses after the lap for the player's orders every few laps, when the safety car
+// comes out and when the weather is about to change
+pub fn is_decision_point(
+    lap: u8,
+    lap_amount: u8,
+    conditions: &[String],
+    safety_car_periods: &[SafetyCarPeriod],
+) -> bool {
+    if lap == 0 || lap >= lap_amount {
+        return false;
+    }
+    lap.is_multiple_of(DECISION_INTERVAL_LAPS)
+        || safety_car_periods.iter().any(|p| p.start_lap == lap)
+        || conditions
+            .get(lap as usize)
+            .is_some_and(|next| *next != conditions[lap as usize - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decision(decision: &str, compound: Option<&str>) -> RaceDecision {
+        RaceDecision {
+            driver_id: 4,
+            lap: 20,
+            decision: decision.to_string(),
+            compound: compound.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn saved_decisions_become_orders() {
+        let order = DriverOrder::from_decision(&decision("Pit Stop", Some("Hard"))).unwrap();
+        assert_eq!((order.driver_id, order.lap), (4, 20));
+        assert!(order.order == RaceOrder::PitStop(Compound::Hard));
+
+        let order = DriverOrder::from_decision(&decision("Push", None)).unwrap();
+        assert!(order.order == RaceOrder::TyreMode(TyreMode::Push));
+        let order = DriverOrder::from_decision(&decision("Let Teammate Pass", None)).unwrap();
+        assert!(order.order == RaceOrder::LetTeammatePass);
+    }
+
+    #[test]
+    fn unknown_decisions_are_not_orders() {
+        assert!(DriverOrder::from_decision(&decision("Pit Stop", None)).is_none());
+        assert!(DriverOrder::from_decision(&decision("Pit Stop", Some("Slick"))).is_none());
+        assert!(DriverOrder::from_decision(&decision("Box", None)).is_none());
+    }
+
+    #[test]
+    fn orders_are_saved_and_loaded_unchanged() {
+        let orders = [
+            RaceOrder::PitStop(Compound::Intermediate),
+            RaceOrder::TyreMode(TyreMode::Conserve),
+            RaceOrder::LetTeammatePass,
+        ];
+        for order in orders {
+            let driver_order = DriverOrder {
+                driver_id: 9,
+                lap: 33,
+                order,
+            };
+            let loaded = DriverOrder::from_decision(&driver_order.to_decision()).unwrap();
+            assert_eq!((loaded.driver_id, loaded.lap), (9, 33));
+            assert!(loaded.order == order);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::backend::race::race_seed;
+use crate::database::driver::{
+    get_all_drivers, get_average_race_placement, get_team_id_by_driver_id, has_driver_development,
+    save_driver_development,
+};
+use crate::database::race::{get_season_race_ids, get_season_schedule_by_id};
+use crate::database::teams::get_team_facilities;
+use crate::model::Driver;
+use chrono::NaiveDate;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// Weights of the attributes in the overall rating, they add up to 1
+const PACE_WEIGHT: f32 = 0.35;
+const RACECRAFT_WEIGHT: f32 = 0.15;
+const CONSISTENCY_WEIGHT: f32 = 0.15;
+const TYRE_MANAGEMENT_WEIGHT: f32 = 0.1;
+const WET_SKILL_WEIGHT: f32 = 0.05;
+const STARTS_WEIGHT: f32 = 0.05;
+const EXPERIENCE_WEIGHT: f32 = 0.15;
+
+// Attribute points a driver gains or loses over a season up to the given age
+const AGE_DEVELOPMENT: [(u8, f32); 6] = [
+    (21, 4.0),
+    (24, 2.5),
+    (27, 1.0),
+    (30, 0.0),
+    (33, -1.0),
+    (36, -2.0),
+];
+const VETERAN_DEVELOPMENT: f32 = -3.0; // For drivers older than the last age above
+const SKILL_DECLINE_FACTOR: f32 = 0.5; // Racecraft and the other skills fade slower than raw pace
+const EXPERIENCE_PER_SEASON: f32 = 3.0;
+const POTENTIAL_HEADROOM: f32 = 5.0; // Rating points below the potential at which growth slows down
+const MID_SEASON_SHARE: f32 = 0.4; // Share of a season's development that happens at mid-season
+const AVERAGE_PLACEMENT: f32 = 10.5;
+const RESULTS_WEIGHT: f32 = 0.5; // Growth gap between a driver who always wins and one always last
+const MIN_FACILITIES_FACTOR: f32 = 0.75; // Growth at a team without any facilities
+const MAX_FACILITIES_FACTOR: f32 = 1.25; // And at one with the best facilities
+const ATTRIBUTE_NOISE: f32 = 1.0; // Random attribute points up or down over a season
+const DEVELOPMENT_SEED_SALT: u64 = 0xDE7E_10B5; // Keeps the development rolls apart from the race rolls
+
+#[derive(Clone, Copy)]
+pub enum DevelopmentPhase {
+    MidSeason,
+    SeasonEnd,
+}
+
+impl DevelopmentPhase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DevelopmentPhase::MidSeason => "Mid-season",
+            DevelopmentPhase::SeasonEnd => "Season end",
+        }
+    }
+
+    fn share(&self) -> f32 {
+        match self {
+            DevelopmentPhase::MidSeason => MID_SEASON_SHARE,
+            DevelopmentPhase::SeasonEnd => 1.0 - MID_SEASON_SHARE,
+        }
+    }
+}
+
+impl Driver {
+    // The attributes with their names, in the order the driver screen shows them
+    pub fn attributes(&self) -> [(&'static str, u8); 7] {
+        [
+            ("Pace", self.pace),
+            ("Racecraft", self.racecraft),
+            ("Consistency", self.consistency),
+            ("Tyre Management", self.tyre_management),
+            ("Wet Skill", self.wet_skill),
+            ("Starts", self.starts),
+            ("Experience", self.experience),
+        ]
+    }
+
+    // Overall rating from 0 to 100, weighted towards raw pace
+    pub fn rating(&self) -> u8 {
+        let rating = self.pace as f32 * PACE_WEIGHT
+            + self.racecraft as f32 * RACECRAFT_WEIGHT
+            + self.consistency as f32 * CONSISTENCY_WEIGHT
+            + self.tyre_management as f32 * TYRE_MANAGEMENT_WEIGHT
+            + self.wet_skill as f32 * WET_SKILL_WEIGHT
+            + self.starts as f32 * STARTS_WEIGHT
+            + self.experience as f32 * EXPERIENCE_WEIGHT;
+        rating.round().min(100.0) as u8
+    }
+
+    // Age in whole years on the date, dates are stored as YYYY-MM-DD
+    pub fn age_on(&self, date: &str) -> u8 {
+        let date_of_birth = NaiveDate::parse_from_str(&self.date_of_birth, "%Y-%m-%d").unwrap();
+        let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+        date.years_since(date_of_birth).unwrap_or(0) as u8
+    }
+}
+
+// The drivers develop after the race in the middle of the season and again after the last one
+fn development_phase(season_schedule_id: u16) -> Option<DevelopmentPhase> {
+    let race = get_season_schedule_by_id(&season_schedule_id).unwrap();
+    let race_ids = get_season_race_ids(&race.season_id);
+    if race_ids.last() == Some(&season_schedule_id) {
+        Some(DevelopmentPhase::SeasonEnd)
+    } else if race_ids.len() >= 2 && race_ids[race_ids.len() / 2 - 1] == season_schedule_id {
+        Some(DevelopmentPhase::MidSeason)
+    } else {
+        None
+    }
+}
+
+// Young drivers grow towards their potential and veterans decline. Good results and the team's
+// facilities speed the growth up. The rolls come from the race seed, so the same career develops
+// the same way, and a race never develops them twice
+pub fn develop_drivers(season_schedule_id: u16) {
+    let Some(phase) = development_phase(season_schedule_id) else {
+        return;
+    };
+    if has_driver_development(&season_schedule_id) {
+        return;
+    }
+    let race = get_season_schedule_by_id(&season_schedule_id).unwrap();
+    let mut rng = StdRng::seed_from_u64(race_seed(&race) as u64 ^ DEVELOPMENT_SEED_SALT);
+    for driver in get_all_drivers() {
+        let results_factor = get_average_race_placement(&driver.id, &race.season_id)
+            .map_or(1.0, |average| {
+                1.0 + (AVERAGE_PLACEMENT - average) / AVERAGE_PLACEMENT * RESULTS_WEIGHT
+            });
+        let facilities = get_team_id_by_driver_id(&driver.id)
+            .and_then(|team_id| get_team_facilities(&team_id))
+            .unwrap_or(0);
+        let facilities_factor = MIN_FACILITIES_FACTOR
+            + facilities.min(100) as f32 / 100.0 * (MAX_FACILITIES_FACTOR - MIN_FACILITIES_FACTOR);
+        let rating_before = driver.rating();
+        let developed = develop_driver(
+            &driver,
+            driver.age_on(&race.date),
+            phase.share() * results_factor * facilities_factor,
+            phase.share(),
+            &mut rng,
+        );
+        save_driver_development(
+            &developed,
+            season_schedule_id,
+            phase.name(),
+            rating_before,
+            developed.rating(),
+        );
+    }
+}
+
+// Growth is scaled by the growth factor and slows down close to the potential, declines and
+// experience only by the share of the season
+fn develop_driver(
+    driver: &Driver,
+    age: u8,
+    growth_factor: f32,
+    share: f32,
+    rng: &mut impl Rng,
+) -> Driver {
+    let development = AGE_DEVELOPMENT
+        .iter()
+        .find(|(max_age, _)| age <= *max_age)
+        .map_or(VETERAN_DEVELOPMENT, |(_, points)| *points);
+    let headroom =
+        ((driver.potential as f32 - driver.rating() as f32) / POTENTIAL_HEADROOM).clamp(0.0, 1.0);
+    let change = |decline_factor: f32| {
+        if development > 0.0 {
+            development * growth_factor * headroom
+        } else {
+            development * share * decline_factor
+        }
+    };
+    let mut develop = |value: u8, change: f32| -> u8 {
+        let change = change + rng.gen_range(-ATTRIBUTE_NOISE..=ATTRIBUTE_NOISE) * share;
+        // small changes add up over the seasons instead of always rounding away
+        let whole = change.floor()
+            + if rng.gen::<f32>() < change.fract() {
+                1.0
+            } else {
+                0.0
+            };
+        (value as f32 + whole).clamp(0.0, 100.0) as u8
+    };
+    Driver {
+        pace: develop(driver.pace, change(1.0)),
+        racecraft: develop(driver.racecraft, change(SKILL_DECLINE_FACTOR)),
+        consistency: develop(driver.consistency, change(SKILL_DECLINE_FACTOR)),
+        tyre_management: develop(driver.tyre_management, change(SKILL_DECLINE_FACTOR)),
+        wet_skill: develop(driver.wet_skill, change(SKILL_DECLINE_FACTOR)),
+        starts: develop(driver.starts, change(1.0)),
+        experience: develop(driver.experience, EXPERIENCE_PER_SEASON * share),
+        ..driver.clone()
+    }
+}
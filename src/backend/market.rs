@@ -0,0 +1,152 @@
+use crate::backend::contract::{ai_offer, sign_contract, team_attractiveness, SEATS_PER_TEAM};
+use crate::backend::race::race_seed;
+use crate::database::driver::{get_all_drivers, get_contracted_drivers};
+use crate::database::news::save_news_item;
+use crate::database::race::{get_season_race_ids, get_season_schedule_by_id};
+use crate::database::teams::{
+    get_all_team_ids, get_selected_team_id, get_team_contract_count, get_team_info,
+};
+use chrono::{Datelike, NaiveDate};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const SIGNING_CHANCE: f64 = 0.35; // Chance per race that a team fills one of its open seats
+const LOYALTY_BONUS: u8 = 5; // Rating points a team gives its own drivers over the others
+const MARKET_SEED_SALT: u64 = 0x51_11E5_EA50; // Keeps the market rolls apart from the race rolls
+
+// The silly season runs from the middle of the season to the last race. After each race the AI
+// teams fill the seats left open for next season, the most attractive teams choosing first.
+// At the last race every open seat is filled so the grid is full when the new season starts. The
+// rolls come from the race seed, so the same career signs the same drivers
+pub fn run_driver_market(season_schedule_id: u16) {
+    let race = get_season_schedule_by_id(&season_schedule_id).unwrap();
+    let race_ids = get_season_race_ids(&race.season_id);
+    let Some(position) = race_ids.iter().position(|id| *id == season_schedule_id) else {
+        return;
+    };
+    if race_ids.len() < 2 || position + 1 < race_ids.len() / 2 {
+        return;
+    }
+    let is_last_race = position + 1 == race_ids.len();
+    let race_date = NaiveDate::parse_from_str(&race.date, "%Y-%m-%d").unwrap();
+    let next_season = NaiveDate::from_ymd_opt(race_date.year() + 1, 1, 1).unwrap();
+    let next_season_date = next_season.to_string();
+
+    let player_team_id = get_selected_team_id();
+    let mut rng = StdRng::seed_from_u64(race_seed(&race) as u64 ^ MARKET_SEED_SALT);
+    for team_id in teams_by_attractiveness() {
+        if Some(team_id) == player_team_id {
+            continue;
+        }
+        let open_seats =
+            SEATS_PER_TEAM.saturating_sub(get_team_contract_count(&team_id, &next_season_date));
+        for _ in 0..open_seats {
+            if !is_last_race && !rng.gen_bool(SIGNING_CHANCE) {
+                continue;
+            }
+            sign_best_driver(team_id, &race.date, next_season, is_last_race);
+        }
+    }
+}
+
+// Fills every seat still open for next season with the best drivers left. The grid has to be
+// full, so the player's open seats are filled too, and the player is told who was signed for them
+pub fn fill_open_seats(date: &str, next_season: NaiveDate) {
+    let player_team_id = get_selected_team_id();
+    for team_id in teams_by_attractiveness() {
+        let open_seats = SEATS_PER_TEAM
+            .saturating_sub(get_team_contract_count(&team_id, &next_season.to_string()));
+        for _ in 0..open_seats {
+            let Some(driver_name) = sign_best_driver(team_id, date, next_season, true) else {
+                continue;
+            };
+            if Some(team_id) == player_team_id {
+                save_news_item(
+                    date,
+                    &format!(
+                        "Your team had no driver for a seat in {}, {} was signed to fill it",
+                        next_season.year(),
+                        driver_name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn teams_by_attractiveness() -> Vec<u16> {
+    let mut teams: Vec<(u16, f32)> = get_all_team_ids()
+        .into_iter()
+        .map(|team_id| (team_id, team_attractiveness(team_id)))
+        .collect();
+    teams.sort_by(|a, b| b.1.total_cmp(&a.1));
+    teams.into_iter().map(|(team_id, _)| team_id).collect()
+}
+
+// Signs the best driver without a seat for next season, announces the move and gives the name of
+// the driver. Unless the seat must be filled now, a driver only moves to a team at least as
+// attractive as their current one
+fn sign_best_driver(
+    team_id: u16,
+    date: &str,
+    next_season: NaiveDate,
+    must_fill: bool,
+) -> Option<String> {
+    let signed_ahead = get_contracted_drivers(&next_season.to_string());
+    let current_teams = get_contracted_drivers(date);
+    let attractiveness = team_attractiveness(team_id);
+    let (driver, current_team_id) = get_all_drivers()
+        .into_iter()
+        .filter(|driver| !signed_ahead.iter().any(|(id, _)| *id == driver.id))
+        .map(|driver| {
+            let current_team_id = current_teams
+                .iter()
+                .find(|(id, _)| *id == driver.id)
+                .map(|(_, team)| *team);
+            (driver, current_team_id)
+        })
+        .filter(|(_, current_team_id)| match current_team_id {
+            Some(current) if *current != team_id && !must_fill => {
+                attractiveness >= team_attractiveness(*current)
+            }
+            _ => true,
+        })
+        .max_by_key(|(driver, current_team_id)| {
+            let loyalty = if *current_team_id == Some(team_id) {
+                LOYALTY_BONUS
+            } else {
+                0
+            };
+            driver.rating() + loyalty
+        })?;
+
+    sign_contract(
+        driver.id,
+        team_id,
+        &ai_offer(driver.id, team_id, next_season),
+        next_season,
+    );
+
+    let name = format!("{} {}", driver.first_name, driver.last_name);
+    let team = get_team_info(&team_id).unwrap().full_name;
+    let message = match current_team_id.and_then(|id| get_team_info(&id)) {
+        Some(current) if current.id == team_id => {
+            format!("{} extends contract with {}", name, team)
+        }
+        Some(current) => format!(
+            "{} leaves {} to join {} for {}",
+            name,
+            current.full_name,
+            team,
+            next_season.year()
+        ),
+        None => format!(
+            "{} sign free agent {} for {}",
+            team,
+            name,
+            next_season.year()
+        ),
+    };
+    save_news_item(date, &message);
+    Some(name)
+}
@@ -1 +1,18 @@
+pub mod contract;
+pub mod decision;
+pub mod driver;
+pub mod market;
+pub mod playback;
+pub mod points;
+pub mod power_unit;
+pub mod practice;
+pub mod predictor;
+pub mod qualifying;
 pub mod race;
+pub mod race_engine;
+pub mod safety_car;
+pub mod season;
+pub mod stewards;
+pub mod strategy;
+pub mod tyre;
+pub mod weather;
@@ -0,0 +1,284 @@
+use crate::model::{RaceResult, SafetyCarPeriod};
+use crate::util::time::format_lap_time;
+
+pub struct PlaybackStanding {
+    pub position: u8,
+    pub driver_name: String,
+    pub team: String,
+    pub gap_ms: Option<u32>, // To the leader, None for the leader, lapped and retired drivers
+    pub interval_ms: Option<u32>, // To the car ahead on the same lap
+    pub laps_down: u8,
+    pub compound: String,
+    pub pit_stops: usize,
+    pub retired: bool,
+}
+
+pub struct PlaybackEvent {
+    pub lap: u8,
+    pub description: String,
+}
+
+// Laps the race was run over, the most any driver completed
+pub fn race_distance(results: &[RaceResult]) -> u8 {
+    results.iter().map(|r| r.laps.len()).max().unwrap_or(0) as u8
+}
+
+// Running order at the end of the given lap, lap 0 is the starting grid. A lapped car has taken
+// the chequered flag a lap or more short of the given lap
+pub fn standings_after_lap(results: &[RaceResult], lap: u8) -> Vec<PlaybackStanding> {
+    // the lap a driver retires on is never completed
+    let is_retired = |r: &RaceResult| r.retired_lap.is_some_and(|retired| retired <= lap);
+    let laps_done = |r: &RaceResult| r.laps.len().min(lap as usize) as u8;
+    let elapsed_ms = |r: &RaceResult| -> u32 {
+        r.laps
+            .iter()
+            .take(lap as usize)
+            .map(|l| l.lap_time_ms)
+            .sum()
+    };
+
+    let mut running: Vec<&RaceResult> = results.iter().filter(|r| !is_retired(r)).collect();
+    if lap == 0 {
+        running.sort_by_key(|r| r.grid_position.unwrap_or(u8::MAX));
+    } else {
+        running.sort_by_key(|r| (std::cmp::Reverse(laps_done(r)), elapsed_ms(r)));
+    }
+    // the latest retirement is classified highest
+    let mut retired: Vec<&RaceResult> = results.iter().filter(|r| is_retired(r)).collect();
+    retired.sort_by_key(|r| std::cmp::Reverse(r.retired_lap));
+
+    let leader_ms = running.first().map_or(0, |r| elapsed_ms(r));
+    let leader_laps = running.first().map_or(0, |r| laps_done(r));
+    let mut standings = Vec::new();
+    let mut ahead: Option<(u8, u32)> = None;
+    for result in running {
+        let total_ms = elapsed_ms(result);
+        let laps = laps_done(result);
+        let laps_down = leader_laps - laps;
+        let on_track = lap > 0 && ahead.is_some();
+        standings.push(PlaybackStanding {
+            position: standings.len() as u8 + 1,
+            driver_name: result.driver_name.clone(),
+            team: result.team.clone(),
+            gap_ms: (on_track && laps_down == 0).then(|| total_ms - leader_ms),
+            interval_ms: ahead
+                .filter(|(ahead_laps, _)| on_track && *ahead_laps == laps)
+                .map(|(_, ahead_ms)| total_ms - ahead_ms),
+            laps_down,
+            compound: current_compound(result, lap),
+            pit_stops: pit_stops(result, lap),
+            retired: false,
+        });
+        ahead = Some((laps, total_ms));
+    }
+    for result in retired {
+        standings.push(PlaybackStanding {
+            position: standings.len() as u8 + 1,
+            driver_name: result.driver_name.clone(),
+            team: result.team.clone(),
+            gap_ms: None,
+            interval_ms: None,
+            laps_down: 0,
+            compound: current_compound(result, lap),
+            pit_stops: pit_stops(result, lap),
+            retired: true,
+        });
+    }
+    standings
+}
+
+// Pit stops, retirements, fastest laps and neutralisations up to the given lap, newest first
+pub fn events_up_to_lap(
+    results: &[RaceResult],
+    safety_car_periods: &[SafetyCarPeriod],
+    lap: u8,
+) -> Vec<PlaybackEvent> {
+    let mut events = Vec::new();
+    for period in safety_car_periods {
+        if period.start_lap <= lap {
+            events.push(PlaybackEvent {
+                lap: period.start_lap,
+                description: format!("{} deployed ({})", period.kind, period.reason),
+            });
+        }
+        if period.end_lap <= lap {
+            events.push(PlaybackEvent {
+                lap: period.end_lap,
+                description: format!("{} ends", period.kind),
+            });
+        }
+    }
+    for result in results {
+        // the driver pits at the end of each stint but the last
+        for stints in result.stints.windows(2) {
+            if stints[0].end_lap <= lap {
+                events.push(PlaybackEvent {
+                    lap: stints[0].end_lap,
+                    description: format!("{} pits for {}", result.driver_name, stints[1].compound),
+                });
+            }
+        }
+        if let (Some(retired_lap), Some(reason)) = (result.retired_lap, &result.retirement_reason) {
+            if retired_lap <= lap {
+                events.push(PlaybackEvent {
+                    lap: retired_lap,
+                    description: format!("{} retires - {}", result.driver_name, reason),
+                });
+            }
+        }
+    }
+    let mut fastest_lap_ms = u32::MAX;
+    for lap_number in 1..=lap {
+        let lap_index = (lap_number - 1) as usize;
+        let fastest = results
+            .iter()
+            .filter_map(|r| r.laps.get(lap_index).map(|l| (r, l.lap_time_ms)))
+            .min_by_key(|(_, lap_time_ms)| *lap_time_ms);
+        if let Some((result, lap_time_ms)) = fastest {
+            if lap_time_ms < fastest_lap_ms {
+                fastest_lap_ms = lap_time_ms;
+                events.push(PlaybackEvent {
+                    lap: lap_number,
+                    description: format!(
+                        "{} sets the fastest lap, {}",
+                        result.driver_name,
+                        format_lap_time(lap_time_ms)
+                    ),
+                });
+            }
+        }
+    }
+    // the sort is stable, so events on the same lap keep their order
+    events.sort_by_key(|e| std::cmp::Reverse(e.lap));
+    events
+}
+
+// Tyres the driver is on for the next lap, or was on when they retired
+fn current_compound(result: &RaceResult, lap: u8) -> String {
+    result
+        .stints
+        .iter()
+        .find(|s| s.end_lap > lap)
+        .or(result.stints.last())
+        .map_or("-".to_string(), |s| s.compound.clone())
+}
+
+fn pit_stops(result: &RaceResult, lap: u8) -> usize {
+    result
+        .stints
+        .iter()
+        .rev()
+        .skip(1)
+        .filter(|s| s.end_lap <= lap)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Lap, Stint};
+
+    fn result(
+        driver_name: &str,
+        lap_times_ms: &[u32],
+        stints: &[(&str, u8)],
+        retired_lap: Option<u8>,
+    ) -> RaceResult {
+        let mut start_lap = 1;
+        RaceResult {
+            position: 1,
+            grid_position: Some(1),
+            driver_number: 1,
+            driver_name: driver_name.to_string(),
+            team: "Team".to_string(),
+            points: 0,
+            total_time_ms: lap_times_ms.iter().sum(),
+            penalty_seconds: 0,
+            status: "Finished".to_string(),
+            retired_lap,
+            retirement_reason: retired_lap.map(|_| "Engine failure".to_string()),
+            laps: lap_times_ms
+                .iter()
+                .enumerate()
+                .map(|(index, lap_time_ms)| Lap {
+                    lap_time_ms: *lap_time_ms,
+                    lap_number: index as u8 + 1,
+                    position: 1,
+                })
+                .collect(),
+            stints: stints
+                .iter()
+                .enumerate()
+                .map(|(index, (compound, end_lap))| {
+                    let stint = Stint {
+                        stint_number: index as u8 + 1,
+                        compound: compound.to_string(),
+                        start_lap,
+                        end_lap: *end_lap,
+                    };
+                    start_lap = end_lap + 1;
+                    stint
+                })
+                .collect(),
+        }
+    }
+
+    fn descriptions(events: &[PlaybackEvent]) -> Vec<(u8, &str)> {
+        events
+            .iter()
+            .map(|e| (e.lap, e.description.as_str()))
+            .collect()
+    }
+
+    fn race() -> (Vec<RaceResult>, Vec<SafetyCarPeriod>) {
+        let results = vec![
+            result(
+                "Anna",
+                &[90_000, 89_000, 89_500, 90_000, 91_000],
+                &[("Soft", 2), ("Hard", 5)],
+                None,
+            ),
+            result("Ben", &[90_500, 89_800, 88_900], &[("Medium", 3)], Some(4)),
+        ];
+        let safety_car_periods = vec![SafetyCarPeriod {
+            kind: "Safety Car".to_string(),
+            start_lap: 4,
+            end_lap: 5,
+            reason: "Engine failure".to_string(),
+        }];
+        (results, safety_car_periods)
+    }
+
+    #[test]
+    fn events_are_listed_newest_first_up_to_the_lap() {
+        let (results, safety_car_periods) = race();
+        let events = events_up_to_lap(&results, &safety_car_periods, 5);
+        assert_eq!(
+            descriptions(&events),
+            [
+                (5, "Safety Car ends"),
+                (4, "Safety Car deployed (Engine failure)"),
+                (4, "Ben retires - Engine failure"),
+                (3, "Ben sets the fastest lap, 1:28.900"),
+                (2, "Anna pits for Hard"),
+                (2, "Anna sets the fastest lap, 1:29.000"),
+                (1, "Anna sets the fastest lap, 1:30.000"),
+            ]
+        );
+    }
+
+    #[test]
+    fn later_events_are_not_shown_yet() {
+        let (results, safety_car_periods) = race();
+        assert!(events_up_to_lap(&results, &safety_car_periods, 0).is_empty());
+        let events = events_up_to_lap(&results, &safety_car_periods, 2);
+        assert_eq!(
+            descriptions(&events),
+            [
+                (2, "Anna pits for Hard"),
+                (2, "Anna sets the fastest lap, 1:29.000"),
+                (1, "Anna sets the fastest lap, 1:30.000"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::model::{PointsSystem, RaceDriverResult};
+
+fn placement_points(points_system: &PointsSystem, placement: u8) -> u16 {
+    points_system
+        .placement_points
+        .get((placement - 1) as usize)
+        .copied()
+        .unwrap_or(0)
+}
+
+// Drivers who took the chequered flag or retired after covering enough of the race distance
+pub fn is_classified(status: &str) -> bool {
+    matches!(status, "Finished" | "Classified")
+}
+
+// Scores the classified drivers of a session, results must be ordered by placement
+pub fn award_points(results: &mut [RaceDriverResult], points_system: &PointsSystem) {
+    for result in results.iter_mut().filter(|r| is_classified(&r.status)) {
+        result.points = placement_points(points_system, result.placement);
+    }
+
+    if points_system.fastest_lap_points == 0 {
+        return;
+    }
+    let fastest_lap = results
+        .iter_mut()
+        .filter(|r| is_classified(&r.status))
+        .filter_map(|r| {
+            let best_lap_ms = r.laps.iter().map(|l| l.lap_time_ms).min()?;
+            Some((best_lap_ms, r))
+        })
+        .min_by_key(|(best_lap_ms, _)| *best_lap_ms);
+    if let Some((_, result)) = fastest_lap {
+        let scores = points_system
+            .fastest_lap_max_placement
+            .is_none_or(|max_placement| result.placement <= max_placement);
+        if scores {
+            result.points += points_system.fastest_lap_points;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Lap;
+
+    fn race_points() -> PointsSystem {
+        PointsSystem {
+            placement_points: vec![25, 18, 15, 12, 10, 8, 6, 4, 2, 1],
+            fastest_lap_points: 1,
+            fastest_lap_max_placement: Some(10),
+        }
+    }
+
+    fn sprint_points() -> PointsSystem {
+        PointsSystem {
+            placement_points: vec![8, 7, 6, 5, 4, 3, 2, 1],
+            fastest_lap_points: 0,
+            fastest_lap_max_placement: None,
+        }
+    }
+
+    // Results in placement order, each driver setting one lap in the given time
+    fn results(drivers: &[(&str, u32)]) -> Vec<RaceDriverResult> {
+        drivers
+            .iter()
+            .enumerate()
+            .map(|(index, (status, lap_time_ms))| RaceDriverResult {
+                driver_id: index as u16 + 1,
+                team_id: 1,
+                placement: index as u8 + 1,
+                points: 0,
+                status: status.to_string(),
+                retired_lap: None,
+                retirement_reason: None,
+                laps: vec![Lap {
+                    lap_time_ms: *lap_time_ms,
+                    lap_number: 1,
+                    position: index as u8 + 1,
+                }],
+                stints: Vec::new(),
+            })
+            .collect()
+    }
+
+    fn points(results: &[RaceDriverResult]) -> Vec<u16> {
+        results.iter().map(|r| r.points).collect()
+    }
+
+    #[test]
+    fn race_and_sprint_score_their_own_points() {
+        let drivers = [("Finished", 90_500); 12];
+        let mut race = results(&drivers);
+        race[2].laps[0].lap_time_ms = 90_000;
+        award_points(&mut race, &race_points());
+        assert_eq!(
+            points(&race),
+            [25, 18, 16, 12, 10, 8, 6, 4, 2, 1, 0, 0],
+            "third place takes the fastest lap point"
+        );
+
+        let mut sprint = results(&drivers);
+        sprint[2].laps[0].lap_time_ms = 90_000;
+        award_points(&mut sprint, &sprint_points());
+        assert_eq!(points(&sprint), [8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn drivers_that_are_not_classified_score_nothing() {
+        let mut race = results(&[
+            ("Finished", 91_000),
+            ("Classified", 91_000),
+            ("NC", 91_000),
+            ("DNF", 89_000),
+            ("DSQ", 89_000),
+        ]);
+        award_points(&mut race, &race_points());
+        // the fastest laps were set by drivers without points, so the bonus goes to the leader
+        assert_eq!(points(&race), [26, 18, 0, 0, 0]);
+    }
+
+    #[test]
+    fn the_fastest_lap_outside_the_points_scores_no_bonus() {
+        let mut drivers = [("Finished", 90_500); 12];
+        drivers[10].1 = 90_000;
+        let mut race = results(&drivers);
+        award_points(&mut race, &race_points());
+        assert_eq!(points(&race), [25, 18, 15, 12, 10, 8, 6, 4, 2, 1, 0, 0]);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::backend::race_engine::SessionResult;
+use crate::database::power_unit::{
+    get_power_unit_components, get_power_unit_components_for_race, insert_power_unit_component,
+    save_power_unit_usage,
+};
+use crate::database::race::get_next_race;
+use crate::model::{Penalty, PowerUnitComponent, TeamReliability};
+
+const FIRST_EXCESS_GRID_PLACES: u8 = 10; // For the first component of a kind over the allocation
+const FURTHER_EXCESS_GRID_PLACES: u8 = 5; // For every one after that
+const NEW_FAILURE_FACTOR: f32 = 0.5; // Engine failure chance of fresh components against the average
+const WORN_FAILURE_FACTOR: f32 = 2.0; // Engine failure chance at the end of the component life
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ComponentKind {
+    InternalCombustionEngine,
+    Turbocharger,
+    MguH,
+    MguK,
+    EnergyStore,
+    ControlElectronics,
+    Exhaust,
+}
+
+impl ComponentKind {
+    pub const ALL: [ComponentKind; 7] = [
+        ComponentKind::InternalCombustionEngine,
+        ComponentKind::Turbocharger,
+        ComponentKind::MguH,
+        ComponentKind::MguK,
+        ComponentKind::EnergyStore,
+        ComponentKind::ControlElectronics,
+        ComponentKind::Exhaust,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ComponentKind::InternalCombustionEngine => "ICE",
+            ComponentKind::Turbocharger => "TC",
+            ComponentKind::MguH => "MGU-H",
+            ComponentKind::MguK => "MGU-K",
+            ComponentKind::EnergyStore => "ES",
+            ComponentKind::ControlElectronics => "CE",
+            ComponentKind::Exhaust => "EX",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ComponentKind> {
+        ComponentKind::ALL
+            .into_iter()
+            .find(|kind| kind.name() == name)
+    }
+
+    // Components of the kind a driver can use over the season without a grid penalty
+    pub fn allocation(&self) -> u8 {
+        match self {
+            ComponentKind::EnergyStore | ComponentKind::ControlElectronics => 2,
+            ComponentKind::Exhaust => 8,
+            _ => 4,
+        }
+    }
+
+    // Distance in km after which the component is worn out
+    pub fn life_km(&self) -> f32 {
+        match self {
+            ComponentKind::EnergyStore | ComponentKind::ControlElectronics => 4000.0,
+            ComponentKind::Exhaust => 1200.0,
+            _ => 2200.0,
+        }
+    }
+}
+
+impl PowerUnitComponent {
+    // Share of the component life used, above 1 once it is worn out
+    pub fn wear(&self) -> f32 {
+        ComponentKind::from_name(&self.kind).map_or(0.0, |kind| self.distance_km / kind.life_km())
+    }
+}
+
+// The driver's components in the car, a new one is fitted for the race in place of a failed one
+// and at the first race of the season
+pub fn fitted_components(
+    season_id: u16,
+    driver_id: u16,
+    season_schedule_id: u16,
+) -> Vec<PowerUnitComponent> {
+    let components = get_power_unit_components(&season_id, &driver_id);
+    let mut replaced = false;
+    for kind in ComponentKind::ALL {
+        let usable = components
+            .iter()
+            .any(|c| c.kind == kind.name() && c.is_fitted && !c.failed);
+        if !usable {
+            let number = components.iter().filter(|c| c.kind == kind.name()).count() as u8 + 1;
+            insert_power_unit_component(
+                season_id,
+                driver_id,
+                kind.name(),
+                number,
+                season_schedule_id,
+            );
+            replaced = true;
+        }
+    }
+    let components = if replaced {
+        get_power_unit_components(&season_id, &driver_id)
+    } else {
+        components
+    };
+    components.into_iter().filter(|c| c.is_fitted).collect()
+}
+
+// Fits a new component of the kind from the next race on, the player's choice on the team screen
+pub fn fit_new_component(season_id: u16, driver_id: u16, kind: ComponentKind) {
+    let Some(next_race) = get_next_race() else {
+        return;
+    };
+    let number = get_power_unit_components(&season_id, &driver_id)
+        .iter()
+        .filter(|c| c.kind == kind.name())
+        .count() as u8
+        + 1;
+    insert_power_unit_component(season_id, driver_id, kind.name(), number, next_race.id);
+}
+
+// The components in the car as they are, without fitting any. The ones due to be replaced are left
+// out, a fresh component adds no wear
+pub fn current_components(season_id: u16, driver_id: u16) -> Vec<PowerUnitComponent> {
+    get_power_unit_components(&season_id, &driver_id)
+        .into_iter()
+        .filter(|c| c.is_fitted && !c.failed)
+        .collect()
+}
+
+// Lowers the power unit reliability as the most worn component in the car ages
+pub fn worn_reliability(
+    reliability: &TeamReliability,
+    components: &[PowerUnitComponent],
+) -> TeamReliability {
+    let wear = components.iter().map(|c| c.wear()).fold(0.0, f32::max);
+    let failure_factor =
+        NEW_FAILURE_FACTOR + (WORN_FAILURE_FACTOR - NEW_FAILURE_FACTOR) * wear * wear;
+    let unreliability = (100 - reliability.power_unit.min(100)) as f32 * failure_factor;
+    TeamReliability {
+        chassis: reliability.chassis,
+        power_unit: (100.0 - unreliability.min(100.0)) as u8,
+    }
+}
+
+// Grid penalties for the components over the allocation that are first used at the race
+pub fn component_grid_penalties(season_schedule_id: u16) -> Vec<Penalty> {
+    let mut penalties: Vec<Penalty> = Vec::new();
+    for component in get_power_unit_components_for_race(&season_schedule_id) {
+        let Some(kind) = ComponentKind::from_name(&component.kind) else {
+            continue;
+        };
+        if component.number <= kind.allocation() {
+            continue;
+        }
+        let places = if component.number == kind.allocation() + 1 {
+            FIRST_EXCESS_GRID_PLACES
+        } else {
+            FURTHER_EXCESS_GRID_PLACES
+        };
+        match penalties
+            .iter_mut()
+            .find(|p| p.driver_id == component.driver_id)
+        {
+            Some(penalty) => {
+                penalty.value += places;
+                penalty.reason =
+                    format!("{}, {} {}", penalty.reason, kind.name(), component.number);
+            }
+            None => penalties.push(Penalty {
+                driver_id: component.driver_id,
+                kind: "Grid Penalty".to_string(),
+                value: places,
+                lap: None,
+                reason: format!(
+                    "Power unit components over the allocation: {} {}",
+                    kind.name(),
+                    component.number
+                ),
+            }),
+        }
+    }
+    penalties
+}
+
+// Adds the distance each driver covered over the weekend to the components in their car. An
+// engine failure is put down to the most worn component
+pub fn record_power_unit_usage(
+    season_id: u16,
+    season_schedule_id: u16,
+    length_km: f32,
+    sessions: &[SessionResult],
+) {
+    let mut usage = Vec::new();
+    let driver_ids: Vec<u16> = sessions
+        .iter()
+        .flat_map(|s| s.driver_results.iter().map(|r| r.driver_id))
+        .collect::<std::collections::BTreeSet<u16>>()
+        .into_iter()
+        .collect();
+    for driver_id in driver_ids {
+        let results = sessions
+            .iter()
+            .flat_map(|s| s.driver_results.iter().filter(|r| r.driver_id == driver_id));
+        let mut laps = 0;
+        let mut engine_failure = false;
+        for result in results {
+            laps += result.laps.len();
+            engine_failure |= result.retirement_reason.as_deref() == Some("Engine failure");
+        }
+        let distance_km = laps as f32 * length_km;
+
+        let components: Vec<PowerUnitComponent> = get_power_unit_components(&season_id, &driver_id)
+            .into_iter()
+            .filter(|c| c.is_fitted)
+            .collect();
+        let most_worn = components
+            .iter()
+            .max_by(|a, b| a.wear().partial_cmp(&b.wear()).unwrap())
+            .map(|c| c.id);
+        for component in &components {
+            let failed = engine_failure && Some(component.id) == most_worn;
+            usage.push((component.id, distance_km, failed));
+        }
+    }
+    save_power_unit_usage(season_schedule_id, &usage);
+}
@@ -0,0 +1,281 @@
+use crate::backend::race::load_race_input;
+use crate::backend::race_engine::{generate_lap_time, Entrant};
+use crate::backend::tyre::{tyre_factor, tyre_for_condition, Compound};
+use crate::backend::weather::{generate_conditions, Condition};
+use crate::database::practice::{
+    get_practice_plans, get_practice_sessions_run, save_practice_plan, save_practice_results,
+};
+use crate::database::race::get_season_schedule_by_id;
+use crate::model::{Circuit, PracticeDriverResult, PracticePlan};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const PRACTICE_SEED_SALT: u64 = 0x94D0_49BB_1331_11EB;
+pub const TEAM_PRACTICE_LAPS: u8 = 50; // Laps a team can run in a session, shared by its drivers
+pub const PRACTICE_LAP_STEP: u8 = 5; // Laps the player moves at a time
+const PRACTICE_RANDOMNESS_FACTOR: f32 = 0.012; // 1.2% variability
+const SETUP_LEARNING_RATE: f32 = 0.03; // Share of the missing setup knowledge a focused lap finds
+const WET_LEARNING_FACTOR: f32 = 0.5; // A wet track teaches less about the dry setup
+const MAX_SETUP_BONUS: f32 = 0.004; // Lap time gained with full setup knowledge, 0.4%
+
+// What a driver works on in a practice session
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PracticeProgramme {
+    QualifyingSetup, // Low fuel runs on new tyres
+    RaceSetup,       // Long runs on high fuel
+    Balanced,
+}
+
+impl PracticeProgramme {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PracticeProgramme::QualifyingSetup => "Qualifying Setup",
+            PracticeProgramme::RaceSetup => "Race Setup",
+            PracticeProgramme::Balanced => "Balanced",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<PracticeProgramme> {
+        match name {
+            "Qualifying Setup" => Some(PracticeProgramme::QualifyingSetup),
+            "Race Setup" => Some(PracticeProgramme::RaceSetup),
+            "Balanced" => Some(PracticeProgramme::Balanced),
+            _ => None,
+        }
+    }
+
+    pub fn next(&self) -> PracticeProgramme {
+        match self {
+            PracticeProgramme::QualifyingSetup => PracticeProgramme::RaceSetup,
+            PracticeProgramme::RaceSetup => PracticeProgramme::Balanced,
+            PracticeProgramme::Balanced => PracticeProgramme::QualifyingSetup,
+        }
+    }
+
+    // How much a lap teaches about the qualifying and the race setup
+    fn learning(&self) -> (f32, f32) {
+        match self {
+            PracticeProgramme::QualifyingSetup => (1.0, 0.2),
+            PracticeProgramme::RaceSetup => (0.2, 1.0),
+            PracticeProgramme::Balanced => (0.6, 0.6),
+        }
+    }
+
+    // Factor to multiply a lap time with for the fuel load the programme runs on
+    fn pace_factor(&self) -> f32 {
+        match self {
+            PracticeProgramme::QualifyingSetup => 0.985,
+            PracticeProgramme::RaceSetup => 1.01,
+            PracticeProgramme::Balanced => 1.0,
+        }
+    }
+}
+
+// How well a driver's car is set up for the circuit, from 0 to 100
+#[derive(Clone, Copy, Default)]
+pub struct SetupKnowledge {
+    pub qualifying: f32,
+    pub race: f32,
+}
+
+impl SetupKnowledge {
+    pub fn qualifying_pace_factor(&self) -> f32 {
+        1.0 - self.qualifying / 100.0 * MAX_SETUP_BONUS
+    }
+
+    pub fn race_pace_factor(&self) -> f32 {
+        1.0 - self.race / 100.0 * MAX_SETUP_BONUS
+    }
+
+    // Each lap finds a share of the knowledge still missing
+    fn learn(
+        &self,
+        programme: PracticeProgramme,
+        laps: u8,
+        condition: Condition,
+    ) -> SetupKnowledge {
+        let condition_factor = match condition {
+            Condition::Dry => 1.0,
+            _ => WET_LEARNING_FACTOR,
+        };
+        let gain = |knowledge: f32, weight: f32| {
+            let found =
+                1.0 - (1.0 - SETUP_LEARNING_RATE * weight * condition_factor).powi(laps as i32);
+            knowledge + (100.0 - knowledge) * found
+        };
+        let (qualifying_weight, race_weight) = programme.learning();
+        SetupKnowledge {
+            qualifying: gain(self.qualifying, qualifying_weight),
+            race: gain(self.race, race_weight),
+        }
+    }
+}
+
+// The running a driver does in a practice session
+#[derive(Clone)]
+pub struct PracticeRun {
+    pub driver_id: u16,
+    pub laps: u8,
+    pub programme: PracticeProgramme,
+}
+
+// Sprint weekends only have the one practice session
+pub fn practice_sessions(is_sprint: bool) -> &'static [&'static str] {
+    if is_sprint {
+        &["FP1"]
+    } else {
+        &["FP1", "FP2", "FP3"]
+    }
+}
+
+// The teams share their running evenly between their drivers on a balanced programme
+fn default_practice_plan(driver_id: u16) -> PracticePlan {
+    PracticePlan {
+        driver_id,
+        laps: TEAM_PRACTICE_LAPS / 2,
+        programme: PracticeProgramme::Balanced.name().to_string(),
+    }
+}
+
+// Runs the session and returns the drivers ordered by their best lap. Drivers without a run
+// follow the default plan
+pub fn simulate_practice_session(
+    circuit: &Circuit,
+    entrants: &[Entrant],
+    runs: &[PracticeRun],
+    condition: Condition,
+    rng: &mut StdRng,
+) -> Vec<PracticeDriverResult> {
+    let mut results: Vec<PracticeDriverResult> = entrants
+        .iter()
+        .map(|entrant| {
+            let run = runs
+                .iter()
+                .find(|r| r.driver_id == entrant.driver.id)
+                .cloned()
+                .unwrap_or(PracticeRun {
+                    driver_id: entrant.driver.id,
+                    laps: TEAM_PRACTICE_LAPS / 2,
+                    programme: PracticeProgramme::Balanced,
+                });
+            let compound = match run.programme {
+                PracticeProgramme::QualifyingSetup => Compound::Soft,
+                _ => Compound::Medium,
+            };
+            let setup_factor = match run.programme {
+                PracticeProgramme::QualifyingSetup => entrant.setup.qualifying_pace_factor(),
+                _ => entrant.setup.race_pace_factor(),
+            };
+            let mut best_lap_time: Option<f32> = None;
+            for _ in 0..run.laps {
+                let lap_time = generate_lap_time(
+                    &entrant.driver,
+                    &entrant.car,
+                    circuit,
+                    condition,
+                    PRACTICE_RANDOMNESS_FACTOR,
+                    rng,
+                ) * tyre_factor(
+                    tyre_for_condition(compound, condition),
+                    0,
+                    circuit.tyre_wear,
+                    condition,
+                ) * run.programme.pace_factor()
+                    * setup_factor;
+                best_lap_time = Some(best_lap_time.map_or(lap_time, |best| best.min(lap_time)));
+            }
+            let setup = entrant.setup.learn(run.programme, run.laps, condition);
+            PracticeDriverResult {
+                driver_id: entrant.driver.id,
+                team_id: entrant.team_id,
+                position: 0,
+                best_lap_ms: best_lap_time.map(|lap_time| (lap_time * 1000.0) as u32),
+                laps: run.laps,
+                programme: run.programme.name().to_string(),
+                qualifying_setup: setup.qualifying,
+                race_setup: setup.race,
+            }
+        })
+        .collect();
+    results.sort_by_key(|r| r.best_lap_ms.unwrap_or(u32::MAX));
+    for (index, result) in results.iter_mut().enumerate() {
+        result.position = (index + 1) as u8;
+    }
+    results
+}
+
+// The first practice session of the weekend that has not been run yet
+pub fn next_practice_session(season_schedule_id: u16) -> Option<&'static str> {
+    let race = get_season_schedule_by_id(&season_schedule_id).unwrap();
+    let sessions_run = get_practice_sessions_run(&season_schedule_id);
+    practice_sessions(race.is_sprint)
+        .iter()
+        .find(|session| !sessions_run.iter().any(|s| s == *session))
+        .copied()
+}
+
+// Runs the session with the player's plans, the other drivers follow the default plan
+pub fn run_practice_session(season_schedule_id: u16, session: &str) {
+    let input = load_race_input(season_schedule_id);
+    let session_number = session.trim_start_matches("FP").parse::<u64>().unwrap_or(0);
+    let mut rng = StdRng::seed_from_u64(
+        (input.seed as u64 ^ PRACTICE_SEED_SALT).wrapping_add(session_number),
+    );
+    let condition = generate_conditions(input.rain_chance, 1, &mut rng)[0];
+
+    let runs: Vec<PracticeRun> = get_practice_plans(&season_schedule_id, session)
+        .iter()
+        .map(|plan| PracticeRun {
+            driver_id: plan.driver_id,
+            laps: plan.laps,
+            programme: PracticeProgramme::from_name(&plan.programme)
+                .unwrap_or(PracticeProgramme::Balanced),
+        })
+        .collect();
+    let results =
+        simulate_practice_session(&input.circuit, &input.entrants, &runs, condition, &mut rng);
+    save_practice_results(season_schedule_id, session, &results);
+}
+
+// Runs the practice sessions the player skipped, so every team arrives at the race with the
+// setup work of a full weekend
+pub fn run_remaining_practice(season_schedule_id: u16) {
+    while let Some(session) = next_practice_session(season_schedule_id) {
+        run_practice_session(season_schedule_id, session);
+    }
+}
+
+// The player's plan for the driver in the session, the default one until they change it
+pub fn practice_plan(season_schedule_id: u16, session: &str, driver_id: u16) -> PracticePlan {
+    get_practice_plans(&season_schedule_id, session)
+        .into_iter()
+        .find(|p| p.driver_id == driver_id)
+        .unwrap_or_else(|| default_practice_plan(driver_id))
+}
+
+// Gives the driver more or fewer laps, taking them from or giving them to their teammate
+pub fn change_practice_laps(
+    season_schedule_id: u16,
+    session: &str,
+    driver_id: u16,
+    teammate_id: Option<u16>,
+    laps: i16,
+) {
+    let mut plan = practice_plan(season_schedule_id, session, driver_id);
+    let new_laps = (plan.laps as i16 + laps).clamp(0, TEAM_PRACTICE_LAPS as i16) as u8;
+    plan.laps = new_laps;
+    save_practice_plan(season_schedule_id, session, &plan);
+    if let Some(teammate_id) = teammate_id {
+        let mut teammate_plan = practice_plan(season_schedule_id, session, teammate_id);
+        teammate_plan.laps = TEAM_PRACTICE_LAPS - new_laps;
+        save_practice_plan(season_schedule_id, session, &teammate_plan);
+    }
+}
+
+pub fn change_practice_programme(season_schedule_id: u16, session: &str, driver_id: u16) {
+    let mut plan = practice_plan(season_schedule_id, session, driver_id);
+    let programme =
+        PracticeProgramme::from_name(&plan.programme).unwrap_or(PracticeProgramme::Balanced);
+    plan.programme = programme.next().name().to_string();
+    save_practice_plan(season_schedule_id, session, &plan);
+}
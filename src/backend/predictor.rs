@@ -0,0 +1,136 @@
+use crate::backend::race::preview_race_input;
+use crate::backend::race_engine::{RaceEngine, RaceInput};
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use std::collections::HashMap;
+
+const PREDICTION_RUNS: u64 = 2000; // Simulated races per prediction
+const PREDICTION_SEED_SALT: u64 = 0x9E37_79B9_7F4A_7C15; // Keeps the predicted races apart from the real one
+const PODIUM_PLACEMENT: u8 = 3;
+
+pub struct DriverPrediction {
+    pub driver_id: u16,
+    pub team_id: u16,
+    pub win_chance: f32,
+    pub podium_chance: f32,
+    pub points_chance: f32,
+    pub expected_points: f32, // Over the whole weekend, sprint included
+}
+
+pub struct TeamPrediction {
+    pub team_id: u16,
+    pub win_chance: f32,
+    pub podium_chance: f32,
+    pub points_chance: f32,
+    pub expected_points: f32,
+}
+
+pub struct RacePrediction {
+    pub drivers: Vec<DriverPrediction>, // Ordered by expected points
+    pub teams: Vec<TeamPrediction>,     // Ordered by expected points
+}
+
+// How one driver did in one simulated weekend
+struct RunOutcome {
+    driver_id: u16,
+    team_id: u16,
+    placement: u8,
+    scored: bool,
+    points: u16,
+}
+
+// Predicts the outcome of the race weekend, nothing is saved to the career
+pub fn predict_race(season_schedule_id: u16) -> RacePrediction {
+    predict(&preview_race_input(season_schedule_id), PREDICTION_RUNS)
+}
+
+// Simulates the weekend many times in parallel, each run on its own seed derived from the input seed
+pub fn predict(input: &RaceInput, runs: u64) -> RacePrediction {
+    let outcomes: Vec<Vec<RunOutcome>> = (0..runs)
+        .into_par_iter()
+        .map(|run| {
+            let mut run_input = input.clone();
+            run_input.seed = (input.seed as u64 ^ PREDICTION_SEED_SALT).wrapping_add(run) as i64;
+            let output = RaceEngine::new(run_input).run();
+
+            let mut weekend_points: HashMap<u16, u16> = HashMap::new();
+            for session in &output.sessions {
+                for result in &session.driver_results {
+                    *weekend_points.entry(result.driver_id).or_insert(0) += result.points;
+                }
+            }
+            output
+                .sessions
+                .iter()
+                .filter(|s| s.session == "Race")
+                .flat_map(|s| &s.driver_results)
+                .map(|result| RunOutcome {
+                    driver_id: result.driver_id,
+                    team_id: result.team_id,
+                    placement: result.placement,
+                    scored: result.points > 0,
+                    points: weekend_points[&result.driver_id],
+                })
+                .collect()
+        })
+        .collect();
+
+    let runs = runs as f32;
+    let mut drivers: Vec<DriverPrediction> = input
+        .entrants
+        .iter()
+        .map(|entrant| DriverPrediction {
+            driver_id: entrant.driver.id,
+            team_id: entrant.team_id,
+            win_chance: 0.0,
+            podium_chance: 0.0,
+            points_chance: 0.0,
+            expected_points: 0.0,
+        })
+        .collect();
+    let mut teams: Vec<TeamPrediction> = Vec::new();
+    for entrant in &input.entrants {
+        if !teams.iter().any(|t| t.team_id == entrant.team_id) {
+            teams.push(TeamPrediction {
+                team_id: entrant.team_id,
+                win_chance: 0.0,
+                podium_chance: 0.0,
+                points_chance: 0.0,
+                expected_points: 0.0,
+            });
+        }
+    }
+
+    for run_outcomes in &outcomes {
+        for outcome in run_outcomes {
+            let driver = drivers
+                .iter_mut()
+                .find(|d| d.driver_id == outcome.driver_id)
+                .unwrap();
+            driver.win_chance += (outcome.placement == 1) as u8 as f32 / runs;
+            driver.podium_chance += (outcome.placement <= PODIUM_PLACEMENT) as u8 as f32 / runs;
+            driver.points_chance += outcome.scored as u8 as f32 / runs;
+            driver.expected_points += outcome.points as f32 / runs;
+        }
+
+        // a team wins, takes a podium or scores when any of its drivers does
+        for team in teams.iter_mut() {
+            let team_outcomes: Vec<&RunOutcome> = run_outcomes
+                .iter()
+                .filter(|o| o.team_id == team.team_id)
+                .collect();
+            team.win_chance += team_outcomes.iter().any(|o| o.placement == 1) as u8 as f32 / runs;
+            team.podium_chance += team_outcomes
+                .iter()
+                .any(|o| o.placement <= PODIUM_PLACEMENT) as u8
+                as f32
+                / runs;
+            team.points_chance += team_outcomes.iter().any(|o| o.scored) as u8 as f32 / runs;
+            team.expected_points +=
+                team_outcomes.iter().map(|o| o.points as f32).sum::<f32>() / runs;
+        }
+    }
+
+    drivers.sort_by(|a, b| b.expected_points.partial_cmp(&a.expected_points).unwrap());
+    teams.sort_by(|a, b| b.expected_points.partial_cmp(&a.expected_points).unwrap());
+    RacePrediction { drivers, teams }
+}
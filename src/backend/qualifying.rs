@@ -0,0 +1,100 @@
+use crate::backend::race_engine::{generate_lap_time, Entrant};
+use crate::backend::tyre::{tyre_factor, tyre_for_condition, Compound};
+use crate::backend::weather::Condition;
+use crate::model::{Circuit, QualifyingDriverResult};
+use rand::rngs::StdRng;
+
+const Q2_DRIVER_AMOUNT: usize = 15; // Drivers going through to Q2
+const Q3_DRIVER_AMOUNT: usize = 10; // Drivers going through to Q3
+const RUNS_PER_SESSION: u8 = 2; // Flying laps per driver in each session
+const QUALIFYING_PACE_FACTOR: f32 = 0.98; // Low fuel and fresh tyres, 2% faster than race pace
+const QUALIFYING_RANDOMNESS_FACTOR: f32 = 0.01; // 1% variability
+
+// Simulates a Q1/Q2/Q3 knockout session and returns the drivers ordered by grid position
+pub fn simulate_qualifying(
+    entrants: &[Entrant],
+    circuit: &Circuit,
+    condition: Condition,
+    rng: &mut StdRng,
+) -> Vec<QualifyingDriverResult> {
+    let q1_times = run_session(entrants.iter().collect(), circuit, condition, rng);
+    let q2_entrants = advancing_entrants(entrants, &q1_times, Q2_DRIVER_AMOUNT);
+    let q2_times = run_session(q2_entrants, circuit, condition, rng);
+    let q3_entrants = advancing_entrants(entrants, &q2_times, Q3_DRIVER_AMOUNT);
+    let q3_times = run_session(q3_entrants, circuit, condition, rng);
+
+    // order by the last session each driver took part in, then by their time in it
+    let mut results: Vec<QualifyingDriverResult> = q1_times
+        .iter()
+        .map(|(driver_id, q1_time_ms)| QualifyingDriverResult {
+            driver_id: *driver_id,
+            team_id: entrants
+                .iter()
+                .find(|e| e.driver.id == *driver_id)
+                .unwrap()
+                .team_id,
+            grid_position: 0,
+            q1_time_ms: *q1_time_ms,
+            q2_time_ms: find_time(&q2_times, driver_id),
+            q3_time_ms: find_time(&q3_times, driver_id),
+        })
+        .collect();
+    results.sort_by_key(|r| match (r.q3_time_ms, r.q2_time_ms) {
+        (Some(q3), _) => (0, q3),
+        (None, Some(q2)) => (1, q2),
+        (None, None) => (2, r.q1_time_ms),
+    });
+    for (index, result) in results.iter_mut().enumerate() {
+        result.grid_position = (index + 1) as u8;
+    }
+    results
+}
+
+fn run_session(
+    entrants: Vec<&Entrant>,
+    circuit: &Circuit,
+    condition: Condition,
+    rng: &mut StdRng,
+) -> Vec<(u16, u32)> {
+    // new softs on a dry track, otherwise new tyres for the conditions
+    let compound = tyre_for_condition(Compound::Soft, condition);
+    let mut session_times = Vec::new();
+    for entrant in entrants {
+        let mut best_lap_time = f32::MAX;
+        for _ in 0..RUNS_PER_SESSION {
+            let lap_time = generate_lap_time(
+                &entrant.driver,
+                &entrant.car,
+                circuit,
+                condition,
+                QUALIFYING_RANDOMNESS_FACTOR,
+                rng,
+            ) * tyre_factor(compound, 0, circuit.tyre_wear, condition)
+                * QUALIFYING_PACE_FACTOR
+                * entrant.setup.qualifying_pace_factor();
+            best_lap_time = best_lap_time.min(lap_time);
+        }
+        session_times.push((entrant.driver.id, (best_lap_time * 1000.0) as u32));
+    }
+    session_times.sort_by_key(|(_, time_ms)| *time_ms);
+    session_times
+}
+
+fn advancing_entrants<'a>(
+    entrants: &'a [Entrant],
+    session_times: &[(u16, u32)],
+    amount: usize,
+) -> Vec<&'a Entrant> {
+    session_times
+        .iter()
+        .take(amount)
+        .filter_map(|(driver_id, _)| entrants.iter().find(|e| e.driver.id == *driver_id))
+        .collect()
+}
+
+fn find_time(session_times: &[(u16, u32)], driver_id: &u16) -> Option<u32> {
+    session_times
+        .iter()
+        .find(|(id, _)| id == driver_id)
+        .map(|(_, time_ms)| *time_ms)
+}
@@ -1,115 +1,206 @@
+use crate::backend::decision::DriverOrder;
+use crate::backend::driver::develop_drivers;
+use crate::backend::market::run_driver_market;
+use crate::backend::power_unit::{
+    component_grid_penalties, current_components, fitted_components, record_power_unit_usage,
+    worn_reliability,
+};
+use crate::backend::practice::{run_remaining_practice, SetupKnowledge};
+use crate::backend::race_engine::{Entrant, RaceEngine, RaceInput, RaceRules};
+use crate::backend::stewards::GridPenalty;
+use crate::backend::strategy::DriverStrategy;
+use crate::backend::weather::race_forecast;
 use crate::database::circuit::get_circuit_by_id;
+use crate::database::decision::{get_race_decisions, save_race_decision};
 use crate::database::driver::get_all_drivers;
-use crate::database::driver::get_team_id_by_driver_id;
-use crate::database::race::{get_season_schedule_by_id, save_driver_results, update_race_status};
-use crate::model::{Driver, Lap, RaceDriverResult};
-use rand::Rng;
-
-const BASE_SPEED: f32 = 200.0; // Average speed in km/h
-const RATING_MAX: u8 = 100; // Maximum driver rating
-const RATING_MIN: u8 = 70; // Minimum driver rating
-const RANDOMNESS_FACTOR: f32 = 0.05; // 5% variability
-
-const POINTS: [u16; 10] = [25, 18, 15, 12, 10, 8, 6, 4, 2, 1];
-
+use crate::database::driver::get_contracted_drivers;
+use crate::database::penalty::{get_grid_penalties, save_penalties};
+use crate::database::points::get_points_system;
+use crate::database::practice::get_setup_knowledge;
+use crate::database::qualifying::save_qualifying_results;
+use crate::database::race::{
+    delete_race_results, get_season_schedule_by_id, get_unfinalised_race_ids, save_driver_results,
+    set_race_final, update_race_seed, update_race_status,
+};
+use crate::database::safety_car::save_safety_car_periods;
+use crate::database::strategy::get_race_strategies;
+use crate::database::teams::{get_car_performance, get_team_reliability};
+use crate::database::weather::save_race_weather;
+use crate::model::{Penalty, PowerUnitComponent, SeasonSchedule};
+
+// Loads the race weekend from the career, runs it through the race engine and saves the results.
+// Practice sessions the player has not run are run first. Nothing else in the career changes, so
+// the race can be simulated again with orders until it is finalised
 pub fn start_race(season_schedule_id: u16) {
     let race = get_season_schedule_by_id(&season_schedule_id).unwrap();
-    let circuit = get_circuit_by_id(&race.circuit_id).unwrap();
-    let drivers = get_all_drivers();
-
-    let driver_lap_times =
-        generate_driver_lap_times(&drivers, circuit.lap_amount, circuit.length_km);
-
-    let driver_total_times = calculate_driver_total_times(&driver_lap_times);
-
-    let driver_results = create_driver_results(&driver_total_times, &driver_lap_times);
+    run_remaining_practice(season_schedule_id);
+    let input = load_race_input(season_schedule_id);
+    let output = RaceEngine::new(input.clone()).run();
+    record_power_unit_usage(
+        race.season_id,
+        season_schedule_id,
+        input.circuit.length_km,
+        &output.sessions,
+    );
+    for session in output.sessions {
+        let condition_names: Vec<&str> = session.conditions.iter().map(|c| c.name()).collect();
+        save_race_weather(season_schedule_id, session.session, &condition_names);
+        save_qualifying_results(
+            season_schedule_id,
+            session.session,
+            &session.qualifying_results,
+        );
+        save_safety_car_periods(
+            season_schedule_id,
+            session.session,
+            &session.safety_car_periods,
+        );
+        save_penalties(season_schedule_id, session.session, &session.penalties);
+        save_driver_results(season_schedule_id, session.session, session.driver_results);
+    }
 
-    save_driver_results(season_schedule_id, driver_results);
     update_race_status(season_schedule_id, "Finished");
 }
 
-fn generate_driver_lap_times(
-    drivers: &[Driver],
-    lap_amount: u8,
-    circuit_length: f32,
-) -> Vec<(u16, Vec<f32>)> {
-    let mut driver_lap_times = Vec::new();
-    for driver in drivers {
-        let mut lap_times = Vec::new();
-        for _ in 0..lap_amount {
-            let lap_time = generate_lap_time(driver.rating, circuit_length);
-            lap_times.push(lap_time);
-        }
-        driver_lap_times.push((driver.id, lap_times));
+// Once the player can no longer change the results of the races run, the drivers develop and
+// the driver market moves on from each of them, exactly once
+pub fn finalise_races() {
+    for season_schedule_id in get_unfinalised_race_ids() {
+        develop_drivers(season_schedule_id);
+        run_driver_market(season_schedule_id);
+        set_race_final(season_schedule_id);
     }
-    driver_lap_times
 }
 
-fn calculate_driver_total_times(driver_lap_times: &[(u16, Vec<f32>)]) -> Vec<(u16, f32)> {
-    let mut driver_total_times = Vec::new();
-    for (driver_id, laps) in driver_lap_times {
-        let total_time: f32 = laps.iter().sum();
-        driver_total_times.push((*driver_id, total_time));
-    }
-    driver_total_times.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
-    driver_total_times
+// Gathers everything the race engine needs for the race weekend from the career. The forecast,
+// the seed and the power unit components for the race are settled and saved on the way
+pub fn load_race_input(season_schedule_id: u16) -> RaceInput {
+    let rain_chance = race_forecast(season_schedule_id);
+    let race = get_season_schedule_by_id(&season_schedule_id).unwrap();
+    let seed = race_seed(&race);
+    let entrants = load_entrants(&race, |driver_id| {
+        fitted_components(race.season_id, driver_id, race.id)
+    });
+    let grid_penalties = grid_penalties(season_schedule_id);
+    race_input(&race, seed, rain_chance, entrants, &grid_penalties)
 }
 
-fn create_driver_results(
-    driver_total_times: &[(u16, f32)],
-    driver_lap_times: &[(u16, Vec<f32>)],
-) -> Vec<(u16, (RaceDriverResult, Vec<Lap>))> {
-    let mut driver_results = Vec::new();
-    for (index, (driver_id, _)) in driver_total_times.iter().enumerate() {
-        let placement = (index + 1) as u8;
-        let points = get_points(placement);
-        let team_id = get_team_id_by_driver_id(driver_id).unwrap();
-
-        let race_driver_result = RaceDriverResult {
-            driver_id: *driver_id,
-            team_id,
-            placement,
-            points,
-            status: "Finished".to_string(),
-        };
-
-        let mut laps = Vec::new();
-        if let Some((_, lap_times)) = driver_lap_times.iter().find(|(id, _)| id == driver_id) {
-            for (lap_number, lap_time) in lap_times.iter().enumerate() {
-                laps.push(Lap {
-                    lap_time_ms: (*lap_time * 1000.0) as u32,
-                    lap_number: (lap_number + 1) as u8,
-                });
-            }
-        }
-
-        driver_results.push((*driver_id, (race_driver_result, laps)));
-    }
-    driver_results
+// The same input without saving anything to the career, for a look ahead at the race. A race
+// without a seed or forecast yet gets stand-ins, and the components that would be replaced at the
+// race are left out along with their grid penalties, only the stewards' penalties are served
+pub fn preview_race_input(season_schedule_id: u16) -> RaceInput {
+    let race = get_season_schedule_by_id(&season_schedule_id).unwrap();
+    let circuit = get_circuit_by_id(&race.circuit_id).unwrap();
+    let entrants = load_entrants(&race, |driver_id| {
+        current_components(race.season_id, driver_id)
+    });
+    race_input(
+        &race,
+        race.seed.unwrap_or_default(),
+        race.rain_chance.unwrap_or(circuit.rain_probability),
+        entrants,
+        &get_grid_penalties(&season_schedule_id),
+    )
 }
 
-fn get_points(placement: u8) -> u16 {
-    if placement <= 10 {
-        POINTS[(placement - 1) as usize]
-    } else {
-        0
+fn race_input(
+    race: &SeasonSchedule,
+    seed: i64,
+    rain_chance: u8,
+    entrants: Vec<Entrant>,
+    grid_penalties: &[Penalty],
+) -> RaceInput {
+    let season_schedule_id = race.id;
+    let circuit = get_circuit_by_id(&race.circuit_id).unwrap();
+    let lap_amount = circuit.lap_amount;
+    RaceInput {
+        circuit,
+        entrants,
+        seed,
+        rain_chance,
+        rules: RaceRules {
+            is_sprint: race.is_sprint,
+            race_points: get_points_system(&race.season_id, "Race"),
+            sprint_points: get_points_system(&race.season_id, "Sprint"),
+        },
+        strategies: get_race_strategies(&season_schedule_id)
+            .iter()
+            .filter_map(|strategy| DriverStrategy::from_race_strategy(strategy, lap_amount))
+            .collect(),
+        orders: get_race_decisions(&season_schedule_id)
+            .iter()
+            .filter_map(DriverOrder::from_decision)
+            .collect(),
+        grid_penalties: grid_penalties
+            .iter()
+            .map(|p| GridPenalty {
+                driver_id: p.driver_id,
+                places: p.value,
+            })
+            .collect(),
     }
 }
 
-fn generate_lap_time(driver_rating: u8, circuit_length: f32) -> f32 {
-    // calculate base lap time (in hours)
-    let base_lap_time = circuit_length / BASE_SPEED;
+// Grid penalties the drivers serve at the race, from the stewards at the previous race and for
+// power unit components over the allocation
+pub fn grid_penalties(season_schedule_id: u16) -> Vec<Penalty> {
+    let mut penalties = get_grid_penalties(&season_schedule_id);
+    penalties.extend(component_grid_penalties(season_schedule_id));
+    penalties
+}
 
-    // adjust based on driver rating, higher rating -> lower lap time (faster)
-    let driver_factor =
-        1.0 - ((driver_rating - RATING_MIN) as f32 / (RATING_MAX - RATING_MIN) as f32) * 0.02;
-    let adjusted_lap_time = base_lap_time * driver_factor;
+// Saves the player's order and simulates the race again with it. The seed is unchanged, so
+// everything before the lap of the order plays out the same
+pub fn give_race_order(season_schedule_id: u16, order: DriverOrder) {
+    save_race_decision(season_schedule_id, &order.to_decision());
+    delete_race_results(season_schedule_id);
+    start_race(season_schedule_id);
+}
 
-    // add randomness (±5% variation)
-    let mut rng = rand::thread_rng();
-    let random_factor = 1.0 + (rng.gen::<f32>() * 2.0 - 1.0) * RANDOMNESS_FACTOR;
-    let final_lap_time = adjusted_lap_time * random_factor;
+// Reuses the stored seed so re-running a race on the same career state gives identical results
+pub fn race_seed(race: &SeasonSchedule) -> i64 {
+    race.seed.unwrap_or_else(|| {
+        let seed = rand::random::<i64>();
+        update_race_seed(race.id, seed);
+        seed
+    })
+}
 
-    // convert to seconds for output
-    final_lap_time * 3600.0
+// The drivers under contract on the race date take part, as long as their team is in the career.
+// The power unit reliability of each car is lowered by the wear on the components fitted to it,
+// and each driver brings the setup knowledge from the weekend's practice
+fn load_entrants(
+    race: &SeasonSchedule,
+    components: impl Fn(u16) -> Vec<PowerUnitComponent>,
+) -> Vec<Entrant> {
+    let setup_knowledge = get_setup_knowledge(&race.id);
+    let contracts = get_contracted_drivers(&race.date);
+    get_all_drivers()
+        .into_iter()
+        .filter_map(|driver| {
+            let team_id = contracts
+                .iter()
+                .find(|(driver_id, _)| *driver_id == driver.id)
+                .map(|(_, team_id)| *team_id)?;
+            let car = get_car_performance(&team_id)?;
+            let reliability = get_team_reliability(&team_id)?;
+            let components = components(driver.id);
+            let setup = setup_knowledge
+                .iter()
+                .find(|(driver_id, _, _)| *driver_id == driver.id)
+                .map_or_else(SetupKnowledge::default, |(_, qualifying, race)| {
+                    SetupKnowledge {
+                        qualifying: *qualifying,
+                        race: *race,
+                    }
+                });
+            Some(Entrant {
+                driver,
+                team_id,
+                car,
+                reliability: worn_reliability(&reliability, &components),
+                setup,
+            })
+        })
+        .collect()
 }
@@ -0,0 +1,1300 @@
+use crate::backend::decision::{DriverOrder, RaceOrder};
+use crate::backend::points::award_points;
+use crate::backend::practice::SetupKnowledge;
+use crate::backend::qualifying::simulate_qualifying;
+use crate::backend::safety_car::{
+    active_neutralisation, deploy_safety_car, Neutralisation, NeutralisedPeriod,
+};
+use crate::backend::stewards::{
+    check_collision_blame, check_pit_stop, check_technical_infringement, check_track_limits,
+    check_tyre_rule, is_disqualified, time_penalty_seconds, GridPenalty, PenaltyKind,
+};
+use crate::backend::strategy::{DriverStrategy, EngineMode, FuelMode};
+use crate::backend::tyre::{
+    default_strategy, is_worn_out, pit_stop_time, sprint_strategy, tyre_factor, tyre_for_condition,
+    Compound, TyreMode,
+};
+use crate::backend::weather::{generate_conditions, Condition};
+use crate::model::{
+    CarPerformance, Circuit, Driver, Lap, Penalty, PointsSystem, QualifyingDriverResult,
+    RaceDriverResult, SafetyCarPeriod, Stint, TeamReliability,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const RATING_MAX: u8 = 100; // Rating at which an attribute gives its full effect
+const RATING_MIN: u8 = 70; // Rating at which an attribute gives no effect, lower ratings count as this
+const RANDOMNESS_FACTOR: f32 = 0.015; // 1.5% variability
+const DRIVER_PACE_WINDOW: f32 = 0.02; // Lap time gap between the worst and best driver
+const MAX_CONSISTENCY_RANDOMNESS: f32 = 1.25; // Share of the lap time variability for the least consistent driver
+const MIN_CONSISTENCY_RANDOMNESS: f32 = 0.75; // And for the most consistent one
+const TYRE_MANAGEMENT_WEAR_WINDOW: f32 = 0.2; // Tyre wear gap between the worst and best tyre management
+const START_TIME_WINDOW: f32 = 1.5; // Seconds off the line between the worst and best starter
+const RACECRAFT_OVERTAKE_FACTOR: f32 = 0.15; // Extra overtaking chance for the best racer against the worst
+const MAX_EXPERIENCE_MISTAKE_FACTOR: f32 = 1.5; // Collision and spin chance of the least experienced driver
+const MIN_EXPERIENCE_MISTAKE_FACTOR: f32 = 0.6; // And of the most experienced one
+const CAR_PACE_WINDOW: f32 = 0.03; // Lap time gap between the worst and best car
+const AERO_WEIGHT: f32 = 0.4;
+const POWER_WEIGHT: f32 = 0.35;
+const MECHANICAL_GRIP_WEIGHT: f32 = 0.25;
+const WET_SKILL_AVERAGE: f32 = 80.0; // Wet skill that neither gains nor loses time in the wet
+const WET_SKILL_PACE_WINDOW: f32 = 0.03; // Lap time gap in the wet between the worst and best wet skill
+const MIN_STINT_LAPS: u8 = 5; // Shortest stint on slicks a team plans after the rain
+const SPRINT_DISTANCE_KM: f32 = 100.0; // Sprints run the fewest laps that cover this distance
+const GRID_SLOT_TIME_LOSS: f32 = 0.5; // Seconds lost on the first lap per grid slot behind pole
+const CLASSIFICATION_DISTANCE: f32 = 0.9; // Share of the winner's laps a driver needs to be classified
+
+// Wheel-to-wheel racing, gaps are in seconds
+const MIN_GAP: f32 = 0.2; // Closest a car can follow the car ahead across the line
+const SAFETY_CAR_GAP: f32 = 0.8; // Gap between the cars lined up behind the safety car
+const NEUTRALISED_PIT_WINDOW: u8 = 10; // Teams bring a planned stop this many laps forward for a cheap stop
+const TEAM_ORDER_WINDOW: f32 = 3.0; // A driver only lets a teammate through who is this close behind
+const DIRTY_AIR_WINDOW: f32 = 1.5; // Following closer than this costs lap time
+const DIRTY_AIR_TIME_LOSS: f32 = 0.15;
+const DRS_WINDOW: f32 = 1.0; // Gap to the car ahead needed to open DRS
+const DRS_ENABLED_LAP: u8 = 3; // DRS is enabled from this lap
+const DRS_OVERTAKE_BONUS: f32 = 0.25;
+const OVERTAKE_BASE_CHANCE: f32 = 0.1;
+const OVERTAKE_PACE_FACTOR: f32 = 0.3; // Extra chance per second of pace advantage
+const OVERTAKE_MAX_CHANCE: f32 = 0.9;
+
+// Per-lap retirement chances, the failure rates are scaled by how far below 100 the reliability is
+const ENGINE_FAILURE_RATE: f32 = 0.00006;
+const CHASSIS_FAILURE_RATE: f32 = 0.00004;
+const COLLISION_CHANCE: f32 = 0.0002;
+const FIRST_LAP_COLLISION_CHANCE: f32 = 0.003;
+const STREET_CIRCUIT_COLLISION_FACTOR: f32 = 1.5; // Walls punish mistakes harder than run-off
+const WET_COLLISION_FACTOR: f32 = 2.0; // Extra collision chance on a fully wet track
+const SLICK_SPIN_CHANCE: f32 = 0.02; // Chance to spin off on slicks on a fully wet track
+const CHASSIS_FAILURES: [&str; 3] = ["Gearbox failure", "Hydraulics failure", "Brake failure"];
+
+#[derive(Clone)]
+pub struct Entrant {
+    pub driver: Driver,
+    pub team_id: u16,
+    pub car: CarPerformance,
+    pub reliability: TeamReliability,
+    pub setup: SetupKnowledge,
+}
+
+// Everything needed to simulate a race weekend, the engine never touches the career database
+#[derive(Clone)]
+pub struct RaceInput {
+    pub circuit: Circuit,
+    pub entrants: Vec<Entrant>,
+    pub seed: i64,
+    pub rain_chance: u8, // Forecast chance of rain in percent
+    pub rules: RaceRules,
+    pub strategies: Vec<DriverStrategy>, // The player's plans for the race, the sprint has none
+    pub orders: Vec<DriverOrder>,        // The player's orders for the race, the sprint has none
+    pub grid_penalties: Vec<GridPenalty>, // Carried over from the previous race, served on the race grid
+}
+
+#[derive(Clone)]
+pub struct RaceRules {
+    pub is_sprint: bool,
+    pub race_points: PointsSystem,
+    pub sprint_points: PointsSystem,
+}
+
+pub struct SessionResult {
+    pub session: &'static str, // Race or Sprint
+    pub conditions: Vec<Condition>,
+    pub qualifying_results: Vec<QualifyingDriverResult>,
+    pub safety_car_periods: Vec<SafetyCarPeriod>,
+    pub penalties: Vec<Penalty>,
+    pub driver_results: Vec<RaceDriverResult>,
+}
+
+pub struct RaceOutput {
+    pub sessions: Vec<SessionResult>, // In the order they were run
+}
+
+pub struct RaceEngine {
+    input: RaceInput,
+}
+
+impl RaceEngine {
+    pub fn new(input: RaceInput) -> RaceEngine {
+        RaceEngine { input }
+    }
+
+    // Simulates the whole race weekend, a sprint weekend runs its sprint qualifying and sprint
+    // first. The same input always gives the same output. A circuit without laps has no race
+    pub fn run(&self) -> RaceOutput {
+        let input = &self.input;
+        if input.circuit.lap_amount == 0 {
+            return RaceOutput {
+                sessions: Vec::new(),
+            };
+        }
+        let mut rng = StdRng::seed_from_u64(input.seed as u64);
+        let mut entrants = input.entrants.clone();
+
+        let mut sessions = Vec::new();
+        if input.rules.is_sprint {
+            let sprint_lap_amount = (SPRINT_DISTANCE_KM / input.circuit.length_km).ceil() as u8;
+            sessions.push(simulate_session(
+                "Sprint",
+                input,
+                sprint_lap_amount,
+                &input.rules.sprint_points,
+                &mut entrants,
+                &mut rng,
+            ));
+        }
+        sessions.push(simulate_session(
+            "Race",
+            input,
+            input.circuit.lap_amount,
+            &input.rules.race_points,
+            &mut entrants,
+            &mut rng,
+        ));
+        RaceOutput { sessions }
+    }
+}
+
+// Runs the qualifying for a race of the weekend and then the race itself
+fn simulate_session(
+    session: &'static str,
+    input: &RaceInput,
+    lap_amount: u8,
+    points_system: &PointsSystem,
+    entrants: &mut [Entrant],
+    rng: &mut StdRng,
+) -> SessionResult {
+    let circuit = &input.circuit;
+    // the player's plans and orders and the grid penalties are for the grand prix, not the sprint
+    let (plans, orders, grid_penalties): (&[DriverStrategy], &[DriverOrder], &[GridPenalty]) =
+        match session {
+            "Race" => (&input.strategies, &input.orders, &input.grid_penalties),
+            _ => (&[], &[], &[]),
+        };
+    let conditions = generate_conditions(input.rain_chance, lap_amount, rng);
+
+    // qualifying is run in the conditions the race starts in
+    let mut qualifying_results = simulate_qualifying(entrants, circuit, conditions[0], rng);
+
+    // line the drivers up in grid order, then drop the penalised drivers back
+    entrants.sort_by_key(|entrant| {
+        qualifying_results
+            .iter()
+            .find(|r| r.driver_id == entrant.driver.id)
+            .map(|r| r.grid_position)
+    });
+    if !grid_penalties.is_empty() {
+        apply_grid_penalties(entrants, grid_penalties);
+        for (index, entrant) in entrants.iter().enumerate() {
+            if let Some(result) = qualifying_results
+                .iter_mut()
+                .find(|r| r.driver_id == entrant.driver.id)
+            {
+                result.grid_position = (index + 1) as u8;
+            }
+        }
+    }
+
+    // a sprint is too short for the mandatory stop, so it is run on a single set of tyres. The
+    // team's strategy is picked even for a driver the player planned for, so the other drivers
+    // get the same strategies whatever the player plans
+    let strategies: Vec<DriverStrategy> = entrants
+        .iter()
+        .map(|entrant| {
+            let stints = match session {
+                "Sprint" => sprint_strategy(lap_amount, rng),
+                _ => default_strategy(lap_amount, circuit.tyre_wear, rng),
+            };
+            plans
+                .iter()
+                .find(|plan| plan.driver_id == entrant.driver.id)
+                .cloned()
+                .unwrap_or(DriverStrategy {
+                    driver_id: entrant.driver.id,
+                    stints,
+                    fuel_mode: FuelMode::Standard,
+                    engine_mode: EngineMode::Standard,
+                })
+        })
+        .collect();
+
+    let (mut driver_races, neutralised_periods, mut penalties) = generate_driver_lap_times(
+        entrants,
+        &strategies,
+        circuit,
+        lap_amount,
+        &conditions,
+        orders,
+        rng,
+    );
+    let safety_car_periods: Vec<SafetyCarPeriod> = neutralised_periods
+        .iter()
+        .map(|p| SafetyCarPeriod {
+            kind: p.neutralisation.name().to_string(),
+            start_lap: p.start_lap,
+            end_lap: p.end_lap,
+            reason: p.reason.to_string(),
+        })
+        .collect();
+
+    apply_chequered_flag(&mut driver_races, lap_amount);
+
+    // the cars that finished go through the post-race technical checks, and at the grand prix
+    // the stewards check they ran the compounds the rules require
+    for driver_race in driver_races.iter().filter(|r| r.retirement.is_none()) {
+        if let Some(penalty) = check_technical_infringement(driver_race.driver_id, rng) {
+            penalties.push(penalty);
+        }
+        if session == "Race" {
+            penalties.extend(check_tyre_rule(driver_race.driver_id, &driver_race.stints));
+        }
+    }
+
+    let driver_total_times = calculate_driver_total_times(&driver_races, &penalties);
+
+    let mut driver_results =
+        create_driver_results(&driver_total_times, driver_races, entrants, &penalties);
+    award_points(&mut driver_results, points_system);
+
+    SessionResult {
+        session,
+        conditions,
+        qualifying_results,
+        safety_car_periods,
+        penalties,
+        driver_results,
+    }
+}
+
+// Drops each penalised driver back by their places, at most to the back of the grid
+fn apply_grid_penalties(entrants: &mut [Entrant], grid_penalties: &[GridPenalty]) {
+    for grid_penalty in grid_penalties {
+        let Some(index) = entrants
+            .iter()
+            .position(|e| e.driver.id == grid_penalty.driver_id)
+        else {
+            continue;
+        };
+        let new_index = (index + grid_penalty.places as usize).min(entrants.len() - 1);
+        entrants[index..=new_index].rotate_left(1);
+    }
+}
+
+struct DriverRace {
+    driver_id: u16,
+    lap_times: Vec<f32>,
+    positions: Vec<u8>,
+    stints: Vec<Stint>,
+    retirement: Option<Retirement>,
+    total_time: f32,
+    stint_index: usize,
+    stint_start_lap: u8,
+    compound: Compound,
+    compound_override: Option<Compound>, // Tyres the player ordered, kept until the next planned stop
+    tyre_age: f32,                       // Laps of wear on the tyres
+    tyre_mode: TyreMode,
+    drive_through: bool, // A drive-through penalty to serve on the next lap without a stop
+}
+
+struct Retirement {
+    lap: u8,
+    reason: &'static str,
+}
+
+// The car a driver is racing on track, as it crossed the line at the end of the lap
+struct CarAhead {
+    total_time_before: f32,
+    total_time: f32,
+    lap_time: f32,
+    pitted: bool,
+    racecraft: u8,
+}
+
+// Simulates the race lap by lap in running order, so drivers have to pass the car ahead
+fn generate_driver_lap_times(
+    entrants: &[Entrant],
+    strategies: &[DriverStrategy],
+    circuit: &Circuit,
+    lap_amount: u8,
+    conditions: &[Condition],
+    orders: &[DriverOrder],
+    rng: &mut StdRng,
+) -> (Vec<DriverRace>, Vec<NeutralisedPeriod>, Vec<Penalty>) {
+    let mut driver_races: Vec<DriverRace> = entrants
+        .iter()
+        .zip(strategies)
+        .map(|(entrant, strategy)| DriverRace {
+            driver_id: entrant.driver.id,
+            lap_times: Vec::new(),
+            positions: Vec::new(),
+            stints: Vec::new(),
+            retirement: None,
+            total_time: 0.0,
+            stint_index: 0,
+            stint_start_lap: 1,
+            compound: tyre_for_condition(strategy.stints[0].compound, conditions[0]),
+            compound_override: None,
+            tyre_age: 0.0,
+            tyre_mode: TyreMode::Normal,
+            drive_through: false,
+        })
+        .collect();
+
+    // entrants are in grid order, so the index is also the grid slot
+    let mut running_order: Vec<usize> = (0..entrants.len()).collect();
+    let mut neutralised_periods: Vec<NeutralisedPeriod> = Vec::new();
+    let mut penalties: Vec<Penalty> = Vec::new();
+    for lap_number in 1..=lap_amount {
+        let condition = conditions[(lap_number - 1) as usize];
+        let neutralisation = active_neutralisation(&neutralised_periods, lap_number);
+        let mut car_ahead: Option<CarAhead> = None;
+        // total time of the last car to cross the line without pitting, the one to queue behind
+        let mut on_track_ahead: Option<f32> = None;
+        for (order_index, &index) in running_order.iter().enumerate() {
+            let entrant = &entrants[index];
+            let plan = &strategies[index];
+            let strategy = &plan.stints;
+            let driver_race = &mut driver_races[index];
+
+            // nobody is racing behind the safety car, so nobody makes a mistake
+            let mistake_factor = if neutralisation.is_some() {
+                0.0
+            } else {
+                experience_mistake_factor(&entrant.driver)
+            };
+            if let Some(reason) = check_retirement(
+                &plan.engine_mode.reliability(&entrant.reliability),
+                circuit,
+                condition,
+                driver_race.compound,
+                mistake_factor,
+                lap_number,
+                rng,
+            ) {
+                // the lap the driver retires on is never completed
+                driver_race.retirement = Some(Retirement {
+                    lap: lap_number,
+                    reason,
+                });
+                // the stewards look at whether the car behind caused it
+                if reason == "Collision" {
+                    if let Some(&behind) = running_order.get(order_index + 1) {
+                        let driver_id = entrants[behind].driver.id;
+                        if let Some(penalty) = check_collision_blame(driver_id, lap_number, rng) {
+                            penalties.push(penalty);
+                        }
+                    }
+                }
+                let race_neutralised = neutralised_periods
+                    .last()
+                    .is_some_and(|p| p.end_lap >= lap_number);
+                if !race_neutralised {
+                    if let Some(period) = deploy_safety_car(reason, lap_number, lap_amount, rng) {
+                        neutralised_periods.push(period);
+                    }
+                }
+                continue;
+            }
+
+            let mut ordered_stop = None;
+            for order in orders_for_lap(orders, driver_race.driver_id, lap_number) {
+                match order {
+                    RaceOrder::PitStop(compound) => ordered_stop = Some(compound),
+                    RaceOrder::TyreMode(mode) => driver_race.tyre_mode = mode,
+                    RaceOrder::LetTeammatePass => {}
+                }
+            }
+
+            let mut lap_time = generate_lap_time(
+                &entrant.driver,
+                &entrant.car,
+                circuit,
+                condition,
+                RANDOMNESS_FACTOR,
+                rng,
+            ) * tyre_factor(
+                driver_race.compound,
+                driver_race.tyre_age as u8,
+                circuit.tyre_wear,
+                condition,
+            ) * driver_race.tyre_mode.pace_factor()
+                * plan.fuel_mode.pace_factor(lap_number, lap_amount)
+                * plan.engine_mode.pace_factor()
+                * entrant.setup.race_pace_factor();
+            if lap_number == 1 {
+                lap_time += index as f32 * GRID_SLOT_TIME_LOSS + start_time_loss(&entrant.driver);
+            }
+            driver_race.tyre_age +=
+                driver_race.tyre_mode.wear_factor() * tyre_management_factor(&entrant.driver);
+
+            // pit at the end of a planned stint on a dry track, when the tyres don't suit the
+            // conditions, or when the wet weather tyres are worn out. Under a safety car or
+            // virtual safety car a planned stop that is coming up soon is taken straight away.
+            // A stop the player orders takes the place of the next planned stop
+            let end_lap = strategy[driver_race.stint_index].end_lap;
+            let planned_stop = driver_race.stint_index + 1 < strategy.len()
+                && (ordered_stop.is_some()
+                    || lap_number == end_lap
+                    || neutralisation.is_some()
+                        && condition == Condition::Dry
+                        && end_lap <= lap_number + NEUTRALISED_PIT_WINDOW);
+            if planned_stop {
+                driver_race.stint_index += 1;
+                driver_race.compound_override = None;
+            }
+            let mut next_compound = ordered_stop.unwrap_or_else(|| {
+                tyre_for_condition(
+                    driver_race
+                        .compound_override
+                        .unwrap_or(strategy[driver_race.stint_index].compound),
+                    condition,
+                )
+            });
+            let pitting = lap_number < lap_amount
+                && (ordered_stop.is_some()
+                    || planned_stop && condition == Condition::Dry
+                    || next_compound != driver_race.compound
+                    || !next_compound.is_dry()
+                        && is_worn_out(
+                            driver_race.compound,
+                            driver_race.tyre_age as u8,
+                            circuit.tyre_wear,
+                        ));
+            let mut pit_time = 0.0;
+            if pitting {
+                // back onto slicks after the rain, skip planned stints that are nearly over
+                if ordered_stop.is_none()
+                    && next_compound.is_dry()
+                    && !driver_race.compound.is_dry()
+                {
+                    while strategy[driver_race.stint_index].end_lap < lap_number + MIN_STINT_LAPS
+                        && driver_race.stint_index + 1 < strategy.len()
+                    {
+                        driver_race.stint_index += 1;
+                    }
+                    next_compound = strategy[driver_race.stint_index].compound;
+                }
+                pit_time = pit_stop_time(
+                    circuit.pit_lane_time_loss
+                        * neutralisation.map_or(1.0, |n| n.pit_loss_factor()),
+                    rng,
+                );
+                driver_race.stints.push(Stint {
+                    stint_number: (driver_race.stints.len() + 1) as u8,
+                    compound: driver_race.compound.name().to_string(),
+                    start_lap: driver_race.stint_start_lap,
+                    end_lap: lap_number,
+                });
+                driver_race.stint_start_lap = lap_number + 1;
+                driver_race.compound = next_compound;
+                driver_race.compound_override = ordered_stop;
+                driver_race.tyre_age = 0.0;
+                if let Some(penalty) = check_pit_stop(driver_race.driver_id, lap_number, rng) {
+                    driver_race.drive_through |= penalty.kind == PenaltyKind::DriveThrough.name();
+                    penalties.push(penalty);
+                }
+            }
+
+            match neutralisation {
+                Some(neutralisation) => {
+                    lap_time = neutralised_lap_time(
+                        neutralisation,
+                        on_track_ahead,
+                        driver_race.total_time,
+                        lap_time,
+                        pitting,
+                    );
+                }
+                None => {
+                    // cars in and out of the pits aren't racing each other
+                    if let Some(ahead) = car_ahead.as_ref().filter(|a| !pitting && !a.pitted) {
+                        lap_time = race_car_ahead(
+                            ahead,
+                            driver_race.total_time,
+                            lap_time,
+                            entrant.driver.racecraft,
+                            lap_number,
+                            circuit.overtaking_difficulty,
+                            rng,
+                        );
+                    }
+                }
+            }
+            lap_time += pit_time;
+            if driver_race.drive_through && !pitting {
+                lap_time += circuit.pit_lane_time_loss;
+                driver_race.drive_through = false;
+            }
+            if neutralisation.is_none() {
+                if let Some(penalty) = check_track_limits(driver_race.driver_id, lap_number, rng) {
+                    penalties.push(penalty);
+                }
+            }
+            if !pitting {
+                on_track_ahead = Some(driver_race.total_time + lap_time);
+            }
+
+            car_ahead = Some(CarAhead {
+                total_time_before: driver_race.total_time,
+                total_time: driver_race.total_time + lap_time,
+                lap_time,
+                pitted: pitting,
+                racecraft: entrant.driver.racecraft,
+            });
+            driver_race.total_time += lap_time;
+            driver_race.lap_times.push(lap_time);
+        }
+
+        // a driver told to let their teammate through backs off until the teammate is past
+        for order in orders
+            .iter()
+            .filter(|o| o.lap == lap_number && o.order == RaceOrder::LetTeammatePass)
+        {
+            let Some(index) = driver_races
+                .iter()
+                .position(|r| r.driver_id == order.driver_id && r.retirement.is_none())
+            else {
+                continue;
+            };
+            let Some(teammate) = (0..entrants.len()).find(|&i| {
+                i != index
+                    && entrants[i].team_id == entrants[index].team_id
+                    && driver_races[i].retirement.is_none()
+            }) else {
+                continue;
+            };
+            let gap = driver_races[teammate].total_time - driver_races[index].total_time;
+            if gap > 0.0 && gap < TEAM_ORDER_WINDOW {
+                let driver_race = &mut driver_races[index];
+                driver_race.total_time += gap + MIN_GAP;
+                *driver_race.lap_times.last_mut().unwrap() += gap + MIN_GAP;
+            }
+        }
+
+        running_order.retain(|&index| driver_races[index].retirement.is_none());
+        running_order.sort_by(|&a, &b| {
+            driver_races[a]
+                .total_time
+                .partial_cmp(&driver_races[b].total_time)
+                .unwrap()
+        });
+        for (position, &index) in running_order.iter().enumerate() {
+            driver_races[index].positions.push((position + 1) as u8);
+        }
+    }
+
+    for driver_race in driver_races.iter_mut() {
+        let laps_completed = driver_race.lap_times.len() as u8;
+        if laps_completed >= driver_race.stint_start_lap {
+            driver_race.stints.push(Stint {
+                stint_number: (driver_race.stints.len() + 1) as u8,
+                compound: driver_race.compound.name().to_string(),
+                start_lap: driver_race.stint_start_lap,
+                end_lap: laps_completed,
+            });
+        }
+    }
+    (driver_races, neutralised_periods, penalties)
+}
+
+fn orders_for_lap(
+    orders: &[DriverOrder],
+    driver_id: u16,
+    lap_number: u8,
+) -> impl Iterator<Item = RaceOrder> + '_ {
+    orders
+        .iter()
+        .filter(move |o| o.driver_id == driver_id && o.lap == lap_number)
+        .map(|o| o.order)
+}
+
+// Lap time while the race is neutralised, nobody overtakes. Behind the safety car the field
+// closes up into a queue, under the virtual safety car everyone slows down and keeps their gaps
+fn neutralised_lap_time(
+    neutralisation: Neutralisation,
+    on_track_ahead: Option<f32>,
+    total_time: f32,
+    lap_time: f32,
+    pitting: bool,
+) -> f32 {
+    let slow_lap_time = lap_time * neutralisation.pace_factor();
+    let Some(ahead_total_time) = on_track_ahead else {
+        return slow_lap_time;
+    };
+    if pitting {
+        return slow_lap_time;
+    }
+    match neutralisation {
+        Neutralisation::SafetyCar => (ahead_total_time + SAFETY_CAR_GAP - total_time).max(lap_time),
+        Neutralisation::VirtualSafetyCar => {
+            (ahead_total_time + MIN_GAP - total_time).max(slow_lap_time)
+        }
+    }
+}
+
+// Returns the lap time after dealing with the car ahead, a driver who can't get past is held up
+fn race_car_ahead(
+    ahead: &CarAhead,
+    total_time: f32,
+    lap_time: f32,
+    racecraft: u8,
+    lap_number: u8,
+    overtaking_difficulty: u8,
+    rng: &mut StdRng,
+) -> f32 {
+    let gap = total_time - ahead.total_time_before;
+    let mut lap_time = lap_time;
+    if gap < DIRTY_AIR_WINDOW {
+        lap_time += DIRTY_AIR_TIME_LOSS;
+    }
+
+    let held_up_lap_time = ahead.total_time + MIN_GAP - total_time;
+    if lap_time >= held_up_lap_time {
+        return lap_time;
+    }
+    if total_time + lap_time < ahead.total_time {
+        let pace_advantage = ahead.lap_time - lap_time;
+        let racecraft_advantage =
+            rating_share(racecraft as f32) - rating_share(ahead.racecraft as f32);
+        if attempt_overtake(
+            pace_advantage,
+            racecraft_advantage,
+            gap,
+            lap_number,
+            overtaking_difficulty,
+            rng,
+        ) {
+            return lap_time;
+        }
+    }
+    held_up_lap_time
+}
+
+// The circuit's overtaking difficulty runs from 1 (easy) to 10 (near impossible). The racecraft
+// advantage runs from -1 to 1, the better racer is more likely to make a move stick or hold on
+fn attempt_overtake(
+    pace_advantage: f32,
+    racecraft_advantage: f32,
+    gap: f32,
+    lap_number: u8,
+    overtaking_difficulty: u8,
+    rng: &mut StdRng,
+) -> bool {
+    let mut chance = OVERTAKE_BASE_CHANCE
+        + pace_advantage.max(0.0) * OVERTAKE_PACE_FACTOR
+        + racecraft_advantage * RACECRAFT_OVERTAKE_FACTOR;
+    if lap_number >= DRS_ENABLED_LAP && gap < DRS_WINDOW {
+        chance += DRS_OVERTAKE_BONUS;
+    }
+    chance *= 1.0 - overtaking_difficulty.min(10) as f32 / 10.0;
+    rng.gen::<f32>() < chance.min(OVERTAKE_MAX_CHANCE)
+}
+
+fn check_retirement(
+    reliability: &TeamReliability,
+    circuit: &Circuit,
+    condition: Condition,
+    compound: Compound,
+    mistake_factor: f32,
+    lap_number: u8,
+    rng: &mut StdRng,
+) -> Option<&'static str> {
+    let engine_chance = (100 - reliability.power_unit.min(100)) as f32 * ENGINE_FAILURE_RATE;
+    let chassis_chance = (100 - reliability.chassis.min(100)) as f32 * CHASSIS_FAILURE_RATE;
+    let mut collision_chance = if lap_number == 1 {
+        FIRST_LAP_COLLISION_CHANCE
+    } else {
+        COLLISION_CHANCE
+    };
+    if circuit.track_type == "Street" {
+        collision_chance *= STREET_CIRCUIT_COLLISION_FACTOR;
+    }
+    collision_chance *= (1.0 + condition.severity() * WET_COLLISION_FACTOR) * mistake_factor;
+    let spin_chance = if compound.is_dry() {
+        condition.severity() * SLICK_SPIN_CHANCE * mistake_factor
+    } else {
+        0.0
+    };
+
+    let roll = rng.gen::<f32>();
+    if roll < engine_chance {
+        Some("Engine failure")
+    } else if roll < engine_chance + chassis_chance {
+        Some(CHASSIS_FAILURES[rng.gen_range(0..CHASSIS_FAILURES.len())])
+    } else if roll < engine_chance + chassis_chance + collision_chance {
+        Some("Collision")
+    } else if roll < engine_chance + chassis_chance + collision_chance + spin_chance {
+        Some("Spun off")
+    } else {
+        None
+    }
+}
+
+// The chequered flag falls when the leader completes the race distance. Every other car finishes
+// the next time it crosses the line, so a lapped car completes fewer laps, and a car that crossed
+// the line after the flag before it retired still finishes
+fn apply_chequered_flag(driver_races: &mut [DriverRace], lap_amount: u8) {
+    let Some(flag_time) = driver_races
+        .iter()
+        .filter(|r| r.lap_times.len() == lap_amount as usize)
+        .map(|r| r.lap_times.iter().sum::<f32>())
+        .min_by(|a, b| a.partial_cmp(b).unwrap())
+    else {
+        return;
+    };
+    for driver_race in driver_races.iter_mut() {
+        let mut elapsed = 0.0;
+        let Some(finish_index) = driver_race.lap_times.iter().position(|lap_time| {
+            elapsed += lap_time;
+            elapsed >= flag_time
+        }) else {
+            continue;
+        };
+        let finish_lap = (finish_index + 1) as u8;
+        driver_race.lap_times.truncate(finish_lap as usize);
+        driver_race.positions.truncate(finish_lap as usize);
+        driver_race.total_time = driver_race.lap_times.iter().sum();
+        driver_race.retirement = None;
+        driver_race.stints.retain(|s| s.start_lap <= finish_lap);
+        if let Some(stint) = driver_race.stints.last_mut() {
+            stint.end_lap = finish_lap;
+        }
+    }
+}
+
+// Laps a driver has to complete to be classified, a share of the winner's laps rounded down
+fn classification_laps(driver_races: &[DriverRace]) -> usize {
+    let winner_laps = driver_races
+        .iter()
+        .map(|r| r.lap_times.len())
+        .max()
+        .unwrap_or(0);
+    (winner_laps as f32 * CLASSIFICATION_DISTANCE) as usize
+}
+
+// Classified drivers are ordered by laps completed and then by total time with their time
+// penalties, whether they finished or retired. The drivers that are not classified come after
+// them and disqualified drivers last
+fn calculate_driver_total_times(
+    driver_races: &[DriverRace],
+    penalties: &[Penalty],
+) -> Vec<(u16, f32)> {
+    let classification_laps = classification_laps(driver_races);
+    let mut driver_total_times = Vec::new();
+    for driver_race in driver_races {
+        let total_time: f32 = driver_race.lap_times.iter().sum::<f32>()
+            + time_penalty_seconds(penalties, driver_race.driver_id);
+        driver_total_times.push((driver_race, total_time));
+    }
+    driver_total_times.sort_by(|(a, a_time), (b, b_time)| {
+        is_disqualified(penalties, a.driver_id)
+            .cmp(&is_disqualified(penalties, b.driver_id))
+            .then(
+                (a.lap_times.len() < classification_laps)
+                    .cmp(&(b.lap_times.len() < classification_laps)),
+            )
+            .then(b.lap_times.len().cmp(&a.lap_times.len()))
+            .then(a_time.partial_cmp(b_time).unwrap())
+    });
+    driver_total_times
+        .into_iter()
+        .map(|(driver_race, total_time)| (driver_race.driver_id, total_time))
+        .collect()
+}
+
+fn create_driver_results(
+    driver_total_times: &[(u16, f32)],
+    mut driver_races: Vec<DriverRace>,
+    entrants: &[Entrant],
+    penalties: &[Penalty],
+) -> Vec<RaceDriverResult> {
+    let classification_laps = classification_laps(&driver_races);
+    let mut driver_results = Vec::new();
+    for (index, (driver_id, _)) in driver_total_times.iter().enumerate() {
+        let race_index = driver_races
+            .iter()
+            .position(|r| r.driver_id == *driver_id)
+            .unwrap();
+        let driver_race = driver_races.swap_remove(race_index);
+        let placement = (index + 1) as u8;
+        let team_id = entrants
+            .iter()
+            .find(|e| e.driver.id == *driver_id)
+            .unwrap()
+            .team_id;
+
+        let mut laps = Vec::new();
+        for (lap_number, (lap_time, position)) in driver_race
+            .lap_times
+            .iter()
+            .zip(&driver_race.positions)
+            .enumerate()
+        {
+            laps.push(Lap {
+                lap_time_ms: (*lap_time * 1000.0) as u32,
+                lap_number: (lap_number + 1) as u8,
+                position: *position,
+            });
+        }
+
+        let classified = laps.len() >= classification_laps;
+        let race_driver_result = match driver_race.retirement {
+            Some(retirement) => RaceDriverResult {
+                driver_id: *driver_id,
+                team_id,
+                placement,
+                points: 0,
+                status: if is_disqualified(penalties, *driver_id) {
+                    "DSQ".to_string()
+                } else if classified {
+                    "Classified".to_string()
+                } else {
+                    "DNF".to_string()
+                },
+                retired_lap: Some(retirement.lap),
+                retirement_reason: Some(retirement.reason.to_string()),
+                laps,
+                stints: driver_race.stints,
+            },
+            None => RaceDriverResult {
+                driver_id: *driver_id,
+                team_id,
+                placement,
+                points: 0,
+                status: if is_disqualified(penalties, *driver_id) {
+                    "DSQ".to_string()
+                } else if classified {
+                    "Finished".to_string()
+                } else {
+                    "NC".to_string()
+                },
+                retired_lap: None,
+                retirement_reason: None,
+                laps,
+                stints: driver_race.stints,
+            },
+        };
+
+        driver_results.push(race_driver_result);
+    }
+    driver_results
+}
+
+pub fn generate_lap_time(
+    driver: &Driver,
+    car: &CarPerformance,
+    circuit: &Circuit,
+    condition: Condition,
+    randomness_factor: f32,
+    rng: &mut StdRng,
+) -> f32 {
+    // calculate base lap time (in hours)
+    let base_lap_time = circuit.length_km / circuit.average_speed_kmh;
+
+    // adjust based on driver pace, higher pace -> lower lap time (faster)
+    let driver_factor = 1.0 - rating_share(driver.pace as f32) * DRIVER_PACE_WINDOW;
+
+    // adjust based on the car, a better car -> lower lap time (faster)
+    let car_rating = car.aero as f32 * AERO_WEIGHT
+        + car.power as f32 * POWER_WEIGHT
+        + car.mechanical_grip as f32 * MECHANICAL_GRIP_WEIGHT;
+    let car_factor = 1.0 - rating_share(car_rating) * CAR_PACE_WINDOW;
+
+    // on a damp or wet track the driver's wet skill gains or loses time on top of that
+    let wet_factor = 1.0
+        + (WET_SKILL_AVERAGE - driver.wet_skill as f32) / (RATING_MAX - RATING_MIN) as f32
+            * WET_SKILL_PACE_WINDOW
+            * condition.severity();
+
+    let adjusted_lap_time = base_lap_time * driver_factor * car_factor * wet_factor;
+
+    // add randomness (± variation), a more consistent driver varies less
+    let consistency_factor = MAX_CONSISTENCY_RANDOMNESS
+        - rating_share(driver.consistency as f32)
+            * (MAX_CONSISTENCY_RANDOMNESS - MIN_CONSISTENCY_RANDOMNESS);
+    let random_factor =
+        1.0 + (rng.gen::<f32>() * 2.0 - 1.0) * randomness_factor * consistency_factor;
+    let final_lap_time = adjusted_lap_time * random_factor;
+
+    // convert to seconds for output
+    final_lap_time * 3600.0
+}
+
+// Where a rating sits between the minimum and maximum rating, from 0 to 1
+fn rating_share(rating: f32) -> f32 {
+    ((rating - RATING_MIN as f32) / (RATING_MAX - RATING_MIN) as f32).clamp(0.0, 1.0)
+}
+
+// Factor to multiply the tyre wear with, a driver who manages the tyres well wears them slower
+fn tyre_management_factor(driver: &Driver) -> f32 {
+    1.0 + (0.5 - rating_share(driver.tyre_management as f32)) * TYRE_MANAGEMENT_WEAR_WINDOW
+}
+
+// Seconds gained (negative) or lost off the line against an average starter
+fn start_time_loss(driver: &Driver) -> f32 {
+    (0.5 - rating_share(driver.starts as f32)) * START_TIME_WINDOW
+}
+
+// Factor to multiply the collision and spin chances with, experienced drivers make fewer mistakes
+fn experience_mistake_factor(driver: &Driver) -> f32 {
+    MAX_EXPERIENCE_MISTAKE_FACTOR
+        - rating_share(driver.experience as f32)
+            * (MAX_EXPERIENCE_MISTAKE_FACTOR - MIN_EXPERIENCE_MISTAKE_FACTOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: i64 = 20250316;
+
+    fn test_input() -> RaceInput {
+        let entrants = (1..=20u16)
+            .map(|id| {
+                let rating = 100 - id as u8;
+                Entrant {
+                    driver: Driver {
+                        id,
+                        first_name: "Driver".to_string(),
+                        last_name: id.to_string(),
+                        pace: rating,
+                        racecraft: rating,
+                        consistency: rating,
+                        tyre_management: rating,
+                        wet_skill: rating,
+                        starts: rating,
+                        experience: rating,
+                        potential: rating,
+                        country_id: 1,
+                        date_of_birth: "2000-01-01".to_string(),
+                        racing_number: id as u8,
+                        image_path: String::new(),
+                    },
+                    team_id: id.div_ceil(2),
+                    car: CarPerformance {
+                        aero: 85,
+                        power: 85,
+                        mechanical_grip: 85,
+                        reliability: 95,
+                    },
+                    reliability: TeamReliability {
+                        chassis: 95,
+                        power_unit: 95,
+                    },
+                    setup: SetupKnowledge::default(),
+                }
+            })
+            .collect();
+        RaceInput {
+            circuit: Circuit {
+                name: "Test Circuit".to_string(),
+                country_id: 1,
+                city: "Test".to_string(),
+                length_km: 5.0,
+                lap_amount: 50,
+                track_type: "Permanent".to_string(),
+                average_speed_kmh: 210.0,
+                overtaking_difficulty: 5,
+                tyre_wear: 5,
+                pit_lane_time_loss: 20.0,
+                rain_probability: 0,
+                image_path: String::new(),
+            },
+            entrants,
+            seed: SEED,
+            rain_chance: 0,
+            rules: RaceRules {
+                is_sprint: false,
+                race_points: PointsSystem {
+                    placement_points: vec![25, 18, 15, 12, 10, 8, 6, 4, 2, 1],
+                    fastest_lap_points: 1,
+                    fastest_lap_max_placement: Some(10),
+                },
+                sprint_points: PointsSystem {
+                    placement_points: vec![8, 7, 6, 5, 4, 3, 2, 1],
+                    fastest_lap_points: 0,
+                    fastest_lap_max_placement: None,
+                },
+            },
+            strategies: Vec::new(),
+            orders: Vec::new(),
+            grid_penalties: Vec::new(),
+        }
+    }
+
+    fn race_session(output: &RaceOutput) -> &SessionResult {
+        output
+            .sessions
+            .iter()
+            .find(|s| s.session == "Race")
+            .unwrap()
+    }
+
+    fn grid(session: &SessionResult) -> Vec<u16> {
+        let mut grid: Vec<&QualifyingDriverResult> = session.qualifying_results.iter().collect();
+        grid.sort_by_key(|r| r.grid_position);
+        grid.iter().map(|r| r.driver_id).collect()
+    }
+
+    fn driver_race(driver_id: u16, laps: usize, retired: bool) -> DriverRace {
+        DriverRace {
+            driver_id,
+            lap_times: vec![90.0; laps],
+            positions: vec![1; laps],
+            stints: Vec::new(),
+            retirement: retired.then_some(Retirement {
+                lap: laps as u8 + 1,
+                reason: "Engine failure",
+            }),
+            total_time: 90.0 * laps as f32,
+            stint_index: 0,
+            stint_start_lap: 1,
+            compound: Compound::Medium,
+            compound_override: None,
+            tyre_age: 0.0,
+            tyre_mode: TyreMode::Normal,
+            drive_through: false,
+        }
+    }
+
+    #[test]
+    fn same_input_and_seed_give_the_same_race() {
+        let first = RaceEngine::new(test_input()).run();
+        let second = RaceEngine::new(test_input()).run();
+        let summary = |output: &RaceOutput| {
+            let session = race_session(output);
+            let conditions: Vec<&str> = session.conditions.iter().map(|c| c.name()).collect();
+            let results: Vec<(u16, u8, String, u16, Vec<u32>)> = session
+                .driver_results
+                .iter()
+                .map(|r| {
+                    (
+                        r.driver_id,
+                        r.placement,
+                        r.status.clone(),
+                        r.points,
+                        r.laps.iter().map(|l| l.lap_time_ms).collect(),
+                    )
+                })
+                .collect();
+            (conditions, grid(session), results, session.penalties.len())
+        };
+        assert_eq!(summary(&first), summary(&second));
+    }
+
+    #[test]
+    fn a_circuit_without_laps_runs_no_sessions() {
+        let mut input = test_input();
+        input.circuit.lap_amount = 0;
+        assert!(RaceEngine::new(input.clone()).run().sessions.is_empty());
+        input.rules.is_sprint = true;
+        assert!(RaceEngine::new(input).run().sessions.is_empty());
+    }
+
+    #[test]
+    fn grid_order_comes_from_qualifying() {
+        let output = RaceEngine::new(test_input()).run();
+        let session = race_session(&output);
+        let qualified_grid = grid(session);
+        // the drivers through to Q3 start at the front in the order of their Q3 times
+        let mut q3_times: Vec<(u32, u16)> = session
+            .qualifying_results
+            .iter()
+            .filter_map(|r| Some((r.q3_time_ms?, r.driver_id)))
+            .collect();
+        q3_times.sort();
+        let q3_order: Vec<u16> = q3_times.iter().map(|(_, driver_id)| *driver_id).collect();
+        assert_eq!(qualified_grid[..q3_order.len()], q3_order[..]);
+
+        // a grid penalty drops the driver back from where they qualified
+        let mut input = test_input();
+        input.grid_penalties = vec![GridPenalty {
+            driver_id: qualified_grid[0],
+            places: 3,
+        }];
+        let penalised = RaceEngine::new(input).run();
+        let penalised_grid = grid(race_session(&penalised));
+        let expected = [
+            qualified_grid[1],
+            qualified_grid[2],
+            qualified_grid[3],
+            qualified_grid[0],
+        ];
+        assert_eq!(penalised_grid[..4], expected);
+        assert_eq!(penalised_grid[4..], qualified_grid[4..]);
+    }
+
+    #[test]
+    fn points_and_fastest_lap_go_to_the_classified_drivers() {
+        let input = test_input();
+        let points_system = input.rules.race_points.clone();
+        let output = RaceEngine::new(input).run();
+        let results = &race_session(&output).driver_results;
+
+        let classified = |status: &str| matches!(status, "Finished" | "Classified");
+        let fastest_lap = results
+            .iter()
+            .filter(|r| classified(&r.status))
+            .min_by_key(|r| r.laps.iter().map(|l| l.lap_time_ms).min().unwrap())
+            .unwrap();
+        for result in results {
+            let mut expected = if classified(&result.status) {
+                points_system
+                    .placement_points
+                    .get(result.placement as usize - 1)
+                    .copied()
+                    .unwrap_or(0)
+            } else {
+                0
+            };
+            if result.driver_id == fastest_lap.driver_id && result.placement <= 10 {
+                expected += points_system.fastest_lap_points;
+            }
+            assert_eq!(result.points, expected, "driver {}", result.driver_id);
+        }
+        let placements: Vec<u8> = results.iter().map(|r| r.placement).collect();
+        assert_eq!(placements, (1..=20).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn the_flag_ends_the_race_of_lapped_cars_a_lap_or_more_short() {
+        let mut leader = driver_race(1, 50, false);
+        leader.stints = vec![
+            Stint {
+                stint_number: 1,
+                compound: "Medium".to_string(),
+                start_lap: 1,
+                end_lap: 25,
+            },
+            Stint {
+                stint_number: 2,
+                compound: "Hard".to_string(),
+                start_lap: 26,
+                end_lap: 50,
+            },
+        ];
+        // ten seconds a lap slower, so lapped after nine laps and crossing the line for the
+        // last time on lap 45
+        let mut lapped = driver_race(2, 50, false);
+        lapped.lap_times = vec![100.0; 50];
+        lapped.stints = vec![
+            Stint {
+                stint_number: 1,
+                compound: "Soft".to_string(),
+                start_lap: 1,
+                end_lap: 30,
+            },
+            Stint {
+                stint_number: 2,
+                compound: "Hard".to_string(),
+                start_lap: 31,
+                end_lap: 46,
+            },
+            Stint {
+                stint_number: 3,
+                compound: "Soft".to_string(),
+                start_lap: 47,
+                end_lap: 50,
+            },
+        ];
+        // retired on lap 48, after the flag had fallen for them
+        let mut retired_after_flag = driver_race(3, 47, true);
+        retired_after_flag.lap_times = vec![100.0; 47];
+        let retired_before_flag = driver_race(4, 20, true);
+
+        let mut driver_races = vec![leader, lapped, retired_after_flag, retired_before_flag];
+        apply_chequered_flag(&mut driver_races, 50);
+
+        let laps: Vec<usize> = driver_races.iter().map(|r| r.lap_times.len()).collect();
+        assert_eq!(laps, [50, 45, 45, 20]);
+        let retired: Vec<bool> = driver_races
+            .iter()
+            .map(|r| r.retirement.is_some())
+            .collect();
+        assert_eq!(retired, [false, false, false, true]);
+        assert_eq!(driver_races[1].total_time, 4500.0);
+        assert_eq!(driver_races[1].positions.len(), 45);
+        // the stint after the flag is dropped and the last one ends at the flag
+        let stints: Vec<(u8, u8)> = driver_races[1]
+            .stints
+            .iter()
+            .map(|s| (s.start_lap, s.end_lap))
+            .collect();
+        assert_eq!(stints, [(1, 30), (31, 45)]);
+        assert_eq!(driver_races[0].stints[1].end_lap, 50);
+    }
+
+    #[test]
+    fn drivers_need_ninety_percent_of_the_winners_laps_to_be_classified() {
+        let input = test_input();
+        let driver_races = vec![
+            driver_race(1, 50, false),
+            driver_race(2, 44, true),
+            driver_race(3, 45, true),
+            driver_race(4, 43, false),
+        ];
+        assert_eq!(classification_laps(&driver_races), 45);
+
+        let total_times = calculate_driver_total_times(&driver_races, &[]);
+        let order: Vec<u16> = total_times
+            .iter()
+            .map(|(driver_id, _)| *driver_id)
+            .collect();
+        assert_eq!(order, [1, 3, 2, 4]);
+
+        let results = create_driver_results(&total_times, driver_races, &input.entrants, &[]);
+        let statuses: Vec<&str> = results.iter().map(|r| r.status.as_str()).collect();
+        assert_eq!(statuses, ["Finished", "Classified", "DNF", "NC"]);
+    }
+
+    #[test]
+    fn orders_take_effect_from_their_lap() {
+        let baseline = RaceEngine::new(test_input()).run();
+        let baseline_results = &race_session(&baseline).driver_results;
+        // a driver who is still running after the lap of the order and had no stop planned on it
+        let order_lap = 10;
+        let driver = baseline_results
+            .iter()
+            .find(|r| {
+                r.laps.len() > order_lap as usize + 1
+                    && r.stints.iter().all(|s| s.end_lap != order_lap)
+            })
+            .unwrap();
+
+        let mut input = test_input();
+        input.orders = vec![DriverOrder {
+            driver_id: driver.driver_id,
+            lap: order_lap,
+            order: RaceOrder::PitStop(Compound::Hard),
+        }];
+        let ordered = RaceEngine::new(input).run();
+        let ordered_results = &race_session(&ordered).driver_results;
+
+        // every lap before the order plays out the same
+        let laps_before = |results: &[RaceDriverResult]| {
+            let mut laps: Vec<(u16, Vec<u32>)> = results
+                .iter()
+                .map(|r| {
+                    let laps = r.laps.iter().take(order_lap as usize - 1);
+                    (r.driver_id, laps.map(|l| l.lap_time_ms).collect())
+                })
+                .collect();
+            laps.sort();
+            laps
+        };
+        assert_eq!(laps_before(baseline_results), laps_before(ordered_results));
+
+        // and the driver pits at the end of the lap for the tyres they were told to take
+        let stints = &ordered_results
+            .iter()
+            .find(|r| r.driver_id == driver.driver_id)
+            .unwrap()
+            .stints;
+        let next_stint = stints
+            .iter()
+            .find(|s| s.start_lap == order_lap + 1)
+            .unwrap();
+        assert_eq!(next_stint.compound, Compound::Hard.name());
+        assert!(stints.iter().any(|s| s.end_lap == order_lap));
+    }
+}
@@ -0,0 +1,97 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Chances that a retirement brings out the safety car or the virtual safety car
+const CRASH_SAFETY_CAR_CHANCE: f32 = 0.6; // Cars stuck in the barriers after a collision or spin
+const CRASH_VIRTUAL_SAFETY_CAR_CHANCE: f32 = 0.25;
+const FAILURE_SAFETY_CAR_CHANCE: f32 = 0.1; // Cars stopping on track with a failure
+const FAILURE_VIRTUAL_SAFETY_CAR_CHANCE: f32 = 0.4;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Neutralisation {
+    SafetyCar,
+    VirtualSafetyCar,
+}
+
+impl Neutralisation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Neutralisation::SafetyCar => "Safety Car",
+            Neutralisation::VirtualSafetyCar => "Virtual Safety Car",
+        }
+    }
+
+    // Factor to multiply a racing lap time with while the period is active
+    pub fn pace_factor(&self) -> f32 {
+        match self {
+            Neutralisation::SafetyCar => 1.35,
+            Neutralisation::VirtualSafetyCar => 1.3,
+        }
+    }
+
+    // Part of the usual pit lane time loss a stop costs while the rest of the field is slow
+    pub fn pit_loss_factor(&self) -> f32 {
+        match self {
+            Neutralisation::SafetyCar => 0.5,
+            Neutralisation::VirtualSafetyCar => 0.6,
+        }
+    }
+
+    fn lap_range(&self) -> (u8, u8) {
+        match self {
+            Neutralisation::SafetyCar => (3, 5),
+            Neutralisation::VirtualSafetyCar => (1, 3),
+        }
+    }
+}
+
+pub struct NeutralisedPeriod {
+    pub neutralisation: Neutralisation,
+    pub start_lap: u8,
+    pub end_lap: u8,
+    pub reason: &'static str,
+}
+
+// Rolls whether a retirement on the given lap neutralises the race from the next lap on
+pub fn deploy_safety_car(
+    reason: &'static str,
+    lap_number: u8,
+    lap_amount: u8,
+    rng: &mut StdRng,
+) -> Option<NeutralisedPeriod> {
+    if lap_number >= lap_amount {
+        return None;
+    }
+
+    let (safety_car_chance, virtual_safety_car_chance) = match reason {
+        "Collision" | "Spun off" => (CRASH_SAFETY_CAR_CHANCE, CRASH_VIRTUAL_SAFETY_CAR_CHANCE),
+        _ => (FAILURE_SAFETY_CAR_CHANCE, FAILURE_VIRTUAL_SAFETY_CAR_CHANCE),
+    };
+    let roll = rng.gen::<f32>();
+    let neutralisation = if roll < safety_car_chance {
+        Neutralisation::SafetyCar
+    } else if roll < safety_car_chance + virtual_safety_car_chance {
+        Neutralisation::VirtualSafetyCar
+    } else {
+        return None;
+    };
+
+    let (min_laps, max_laps) = neutralisation.lap_range();
+    let laps = rng.gen_range(min_laps..=max_laps);
+    Some(NeutralisedPeriod {
+        neutralisation,
+        start_lap: lap_number + 1,
+        end_lap: lap_number.saturating_add(laps).min(lap_amount),
+        reason,
+    })
+}
+
+pub fn active_neutralisation(
+    periods: &[NeutralisedPeriod],
+    lap_number: u8,
+) -> Option<Neutralisation> {
+    periods
+        .iter()
+        .find(|p| p.start_lap <= lap_number && lap_number <= p.end_lap)
+        .map(|p| p.neutralisation)
+}
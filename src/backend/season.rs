@@ -0,0 +1,54 @@
+use crate::backend::market::fill_open_seats;
+use crate::backend::race::finalise_races;
+use crate::database::config::{get_current_date, update_current_date};
+use crate::database::race::{get_next_race, get_season_race_ids, get_season_schedule_by_id};
+use crate::database::season::{
+    get_current_season, has_final_standings, insert_season, save_final_standings,
+};
+use chrono::{Datelike, Duration, NaiveDate};
+
+// Closes the season once all its races are run. The last race is finalised, so the drivers have
+// their season end development, the final standings are kept and the seats still open for next
+// season are filled. The next season runs the same calendar a year on, and the game moves to its
+// first day
+pub fn start_next_season() -> Option<NaiveDate> {
+    let season = get_current_season()?;
+    let race_ids = get_season_race_ids(&season.id);
+    if race_ids.is_empty() || get_next_race().is_some() {
+        return None;
+    }
+    finalise_races();
+    if !has_final_standings(&season.id) {
+        save_final_standings(&season.id);
+    }
+
+    let next_season = NaiveDate::from_ymd_opt(season.year as i32 + 1, 1, 1).unwrap();
+    fill_open_seats(&get_current_date().unwrap().to_string(), next_season);
+
+    let race_dates: Vec<(u16, String)> = race_ids
+        .iter()
+        .map(|race_id| {
+            let race = get_season_schedule_by_id(race_id).unwrap();
+            (*race_id, date_a_year_on(&race.date).to_string())
+        })
+        .collect();
+    insert_season(season.year + 1, season.id, &race_dates);
+    update_current_date(&next_season);
+    Some(next_season)
+}
+
+// The closest day a year later on the same weekday, so a race stays on its day of the weekend
+fn date_a_year_on(date: &str) -> NaiveDate {
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+    // the 29th of February falls back to the 28th
+    let same_day = date
+        .with_year(date.year() + 1)
+        .unwrap_or_else(|| NaiveDate::from_ymd_opt(date.year() + 1, 2, 28).unwrap());
+    let days_after =
+        (same_day.weekday().num_days_from_monday() + 7 - date.weekday().num_days_from_monday()) % 7;
+    if days_after <= 3 {
+        same_day - Duration::days(days_after as i64)
+    } else {
+        same_day + Duration::days(7 - days_after as i64)
+    }
+}
@@ -0,0 +1,228 @@
+use crate::backend::tyre::Compound;
+use crate::model::{Penalty, Stint};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+// Chances of an incident the stewards punish
+const TRACK_LIMITS_CHANCE: f32 = 0.001; // Per driver per racing lap
+const UNSAFE_RELEASE_CHANCE: f32 = 0.01; // Per pit stop
+const PIT_LANE_SPEEDING_CHANCE: f32 = 0.005; // Per pit stop
+const COLLISION_BLAME_CHANCE: f32 = 0.5; // The car behind is blamed for a collision after the first lap
+const TECHNICAL_INFRINGEMENT_CHANCE: f32 = 0.002; // Per finisher at the post-race checks
+const TECHNICAL_INFRINGEMENTS: [&str; 3] = [
+    "Excessive plank wear",
+    "Car underweight",
+    "Illegal fuel sample",
+];
+
+const TRACK_LIMITS_PENALTY_SECONDS: u8 = 5;
+const UNSAFE_RELEASE_PENALTY_SECONDS: u8 = 5;
+const COLLISION_GRID_PENALTY_PLACES: u8 = 3;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PenaltyKind {
+    TimePenalty,      // Added to the race time
+    DriveThrough,     // Served on the next lap by driving through the pit lane
+    GridPenalty,      // Places dropped on the grid of the next race
+    Disqualification, // Removed from the classification
+}
+
+impl PenaltyKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            PenaltyKind::TimePenalty => "Time Penalty",
+            PenaltyKind::DriveThrough => "Drive Through",
+            PenaltyKind::GridPenalty => "Grid Penalty",
+            PenaltyKind::Disqualification => "Disqualification",
+        }
+    }
+}
+
+// A grid penalty from the previous race, served when lining up for this one
+#[derive(Clone)]
+pub struct GridPenalty {
+    pub driver_id: u16,
+    pub places: u8,
+}
+
+fn penalty(driver_id: u16, kind: PenaltyKind, value: u8, lap: Option<u8>, reason: &str) -> Penalty {
+    Penalty {
+        driver_id,
+        kind: kind.name().to_string(),
+        value,
+        lap,
+        reason: reason.to_string(),
+    }
+}
+
+pub fn check_track_limits(driver_id: u16, lap_number: u8, rng: &mut StdRng) -> Option<Penalty> {
+    (rng.gen::<f32>() < TRACK_LIMITS_CHANCE).then(|| {
+        penalty(
+            driver_id,
+            PenaltyKind::TimePenalty,
+            TRACK_LIMITS_PENALTY_SECONDS,
+            Some(lap_number),
+            "Track limits",
+        )
+    })
+}
+
+pub fn check_pit_stop(driver_id: u16, lap_number: u8, rng: &mut StdRng) -> Option<Penalty> {
+    let roll = rng.gen::<f32>();
+    if roll < UNSAFE_RELEASE_CHANCE {
+        Some(penalty(
+            driver_id,
+            PenaltyKind::TimePenalty,
+            UNSAFE_RELEASE_PENALTY_SECONDS,
+            Some(lap_number),
+            "Unsafe release",
+        ))
+    } else if roll < UNSAFE_RELEASE_CHANCE + PIT_LANE_SPEEDING_CHANCE {
+        Some(penalty(
+            driver_id,
+            PenaltyKind::DriveThrough,
+            0,
+            Some(lap_number),
+            "Speeding in the pit lane",
+        ))
+    } else {
+        None
+    }
+}
+
+// Rolls whether the driver behind is blamed for a collision that put a car out
+pub fn check_collision_blame(driver_id: u16, lap_number: u8, rng: &mut StdRng) -> Option<Penalty> {
+    (lap_number > 1 && rng.gen::<f32>() < COLLISION_BLAME_CHANCE).then(|| {
+        penalty(
+            driver_id,
+            PenaltyKind::GridPenalty,
+            COLLISION_GRID_PENALTY_PLACES,
+            Some(lap_number),
+            "Causing a collision",
+        )
+    })
+}
+
+pub fn check_technical_infringement(driver_id: u16, rng: &mut StdRng) -> Option<Penalty> {
+    (rng.gen::<f32>() < TECHNICAL_INFRINGEMENT_CHANCE).then(|| {
+        let reason = TECHNICAL_INFRINGEMENTS[rng.gen_range(0..TECHNICAL_INFRINGEMENTS.len())];
+        penalty(driver_id, PenaltyKind::Disqualification, 0, None, reason)
+    })
+}
+
+// A finisher of a dry grand prix has to run two different dry compounds, the rule is lifted once
+// the wet weather tyres have been used
+pub fn check_tyre_rule(driver_id: u16, stints: &[Stint]) -> Option<Penalty> {
+    let compounds: Vec<Compound> = stints
+        .iter()
+        .filter_map(|s| Compound::from_name(&s.compound))
+        .collect();
+    if compounds.iter().any(|c| !c.is_dry()) {
+        return None;
+    }
+    let dry_compounds = compounds
+        .iter()
+        .map(|c| c.name())
+        .collect::<std::collections::HashSet<&str>>()
+        .len();
+    (dry_compounds < 2).then(|| {
+        penalty(
+            driver_id,
+            PenaltyKind::Disqualification,
+            0,
+            None,
+            "Did not use two dry compounds",
+        )
+    })
+}
+
+pub fn time_penalty_seconds(penalties: &[Penalty], driver_id: u16) -> f32 {
+    penalties
+        .iter()
+        .filter(|p| p.driver_id == driver_id && p.kind == PenaltyKind::TimePenalty.name())
+        .map(|p| p.value as f32)
+        .sum()
+}
+
+pub fn is_disqualified(penalties: &[Penalty], driver_id: u16) -> bool {
+    penalties
+        .iter()
+        .any(|p| p.driver_id == driver_id && p.kind == PenaltyKind::Disqualification.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    fn stints(compounds: &[&str]) -> Vec<Stint> {
+        compounds
+            .iter()
+            .enumerate()
+            .map(|(index, compound)| Stint {
+                stint_number: index as u8 + 1,
+                compound: compound.to_string(),
+                start_lap: index as u8 * 20 + 1,
+                end_lap: index as u8 * 20 + 20,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn a_dry_race_on_one_compound_is_disqualified() {
+        let penalty = check_tyre_rule(7, &stints(&["Medium", "Medium"])).unwrap();
+        assert_eq!(penalty.driver_id, 7);
+        assert_eq!(penalty.kind, PenaltyKind::Disqualification.name());
+        assert!(check_tyre_rule(7, &stints(&["Soft"])).is_some());
+    }
+
+    #[test]
+    fn two_dry_compounds_or_wet_tyres_meet_the_tyre_rule() {
+        assert!(check_tyre_rule(7, &stints(&["Medium", "Hard"])).is_none());
+        assert!(check_tyre_rule(7, &stints(&["Soft", "Soft", "Hard"])).is_none());
+        assert!(check_tyre_rule(7, &stints(&["Intermediate", "Medium"])).is_none());
+        assert!(check_tyre_rule(7, &stints(&["Wet"])).is_none());
+    }
+
+    #[test]
+    fn only_time_penalties_add_to_the_race_time() {
+        let penalties = vec![
+            penalty(1, PenaltyKind::TimePenalty, 5, Some(3), "Track limits"),
+            penalty(1, PenaltyKind::TimePenalty, 10, Some(9), "Unsafe release"),
+            penalty(
+                1,
+                PenaltyKind::GridPenalty,
+                3,
+                Some(12),
+                "Causing a collision",
+            ),
+            penalty(2, PenaltyKind::TimePenalty, 5, Some(4), "Track limits"),
+        ];
+        assert_eq!(time_penalty_seconds(&penalties, 1), 15.0);
+        assert_eq!(time_penalty_seconds(&penalties, 2), 5.0);
+        assert_eq!(time_penalty_seconds(&penalties, 3), 0.0);
+    }
+
+    #[test]
+    fn only_a_disqualification_disqualifies() {
+        let penalties = vec![
+            penalty(1, PenaltyKind::Disqualification, 0, None, "Car underweight"),
+            penalty(2, PenaltyKind::DriveThrough, 0, Some(5), "Speeding"),
+        ];
+        assert!(is_disqualified(&penalties, 1));
+        assert!(!is_disqualified(&penalties, 2));
+    }
+
+    #[test]
+    fn nobody_is_blamed_for_a_first_lap_collision() {
+        for seed in 0..200 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            assert!(check_collision_blame(1, 1, &mut rng).is_none());
+        }
+        let blamed = (0..200).any(|seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            check_collision_blame(1, 2, &mut rng).is_some()
+        });
+        assert!(blamed);
+    }
+}
@@ -0,0 +1,320 @@
+use crate::backend::race::preview_race_input;
+use crate::backend::race_engine::{RaceEngine, RaceInput};
+use crate::backend::tyre::{Compound, PlannedStint};
+use crate::model::{RaceStrategy, StrategyStint, TeamReliability};
+use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+const STRATEGY_ESTIMATE_RUNS: u64 = 100; // Races simulated with and without the plan
+const STRATEGY_ESTIMATE_SEED_SALT: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const FUEL_LOAD_LAPS: f32 = 0.3; // Share of the race the starting fuel load makes a difference for
+const FUEL_SAVING_LAPS: f32 = 0.1; // Share of the race a lean car has to lift and coast at the end
+
+// How much fuel the car starts with
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FuelMode {
+    Lean,     // Light at the start, has to save fuel at the end
+    Standard, // Fuelled to race flat out to the flag
+    Rich,     // Heavy at the start, can run richer engine maps later
+}
+
+impl FuelMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FuelMode::Lean => "Lean",
+            FuelMode::Standard => "Standard",
+            FuelMode::Rich => "Rich",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<FuelMode> {
+        match name {
+            "Lean" => Some(FuelMode::Lean),
+            "Standard" => Some(FuelMode::Standard),
+            "Rich" => Some(FuelMode::Rich),
+            _ => None,
+        }
+    }
+
+    pub fn next(&self) -> FuelMode {
+        match self {
+            FuelMode::Lean => FuelMode::Standard,
+            FuelMode::Standard => FuelMode::Rich,
+            FuelMode::Rich => FuelMode::Lean,
+        }
+    }
+
+    // Factor to multiply a lap time with on the given lap
+    pub fn pace_factor(&self, lap_number: u8, lap_amount: u8) -> f32 {
+        let progress = lap_number as f32 / lap_amount as f32;
+        match self {
+            FuelMode::Lean if progress <= FUEL_LOAD_LAPS => 0.996,
+            FuelMode::Lean if progress > 1.0 - FUEL_SAVING_LAPS => 1.008,
+            FuelMode::Rich if progress <= FUEL_LOAD_LAPS => 1.002,
+            FuelMode::Rich => 0.998,
+            _ => 1.0,
+        }
+    }
+}
+
+// How hard the power unit is run
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EngineMode {
+    Conservative,
+    Standard,
+    Aggressive,
+}
+
+impl EngineMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EngineMode::Conservative => "Conservative",
+            EngineMode::Standard => "Standard",
+            EngineMode::Aggressive => "Aggressive",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<EngineMode> {
+        match name {
+            "Conservative" => Some(EngineMode::Conservative),
+            "Standard" => Some(EngineMode::Standard),
+            "Aggressive" => Some(EngineMode::Aggressive),
+            _ => None,
+        }
+    }
+
+    pub fn next(&self) -> EngineMode {
+        match self {
+            EngineMode::Conservative => EngineMode::Standard,
+            EngineMode::Standard => EngineMode::Aggressive,
+            EngineMode::Aggressive => EngineMode::Conservative,
+        }
+    }
+
+    // Factor to multiply a lap time with
+    pub fn pace_factor(&self) -> f32 {
+        match self {
+            EngineMode::Conservative => 1.002,
+            EngineMode::Standard => 1.0,
+            EngineMode::Aggressive => 0.996,
+        }
+    }
+
+    // Scales how far the power unit reliability is below 100, which sets the engine failure chance
+    pub fn reliability(&self, reliability: &TeamReliability) -> TeamReliability {
+        let failure_factor = match self {
+            EngineMode::Conservative => 0.5,
+            EngineMode::Standard => 1.0,
+            EngineMode::Aggressive => 2.5,
+        };
+        let unreliability = (100 - reliability.power_unit.min(100)) as f32 * failure_factor;
+        TeamReliability {
+            chassis: reliability.chassis,
+            power_unit: (100.0 - unreliability.min(100.0)) as u8,
+        }
+    }
+}
+
+// The player's plan for a driver as the race engine runs it
+#[derive(Clone)]
+pub struct DriverStrategy {
+    pub driver_id: u16,
+    pub stints: Vec<PlannedStint>,
+    pub fuel_mode: FuelMode,
+    pub engine_mode: EngineMode,
+}
+
+impl DriverStrategy {
+    // A plan whose stops are out of order or past the flag is not run, the team's strategy is
+    pub fn from_race_strategy(strategy: &RaceStrategy, lap_amount: u8) -> Option<DriverStrategy> {
+        let stints = strategy
+            .stints
+            .iter()
+            .map(|stint| {
+                Some(PlannedStint {
+                    compound: Compound::from_name(&stint.compound)?,
+                    end_lap: stint.end_lap,
+                })
+            })
+            .collect::<Option<Vec<PlannedStint>>>()?;
+        let mut previous_end_lap = 0;
+        for stint in &stints {
+            if stint.end_lap <= previous_end_lap || stint.end_lap > lap_amount {
+                return None;
+            }
+            previous_end_lap = stint.end_lap;
+        }
+        if stints.is_empty() {
+            return None;
+        }
+        Some(DriverStrategy {
+            driver_id: strategy.driver_id,
+            stints,
+            fuel_mode: FuelMode::from_name(&strategy.fuel_mode)?,
+            engine_mode: EngineMode::from_name(&strategy.engine_mode)?,
+        })
+    }
+}
+
+// A one-stop plan to start the player's planning from
+pub fn default_race_strategy(driver_id: u16, lap_amount: u8) -> RaceStrategy {
+    RaceStrategy {
+        driver_id,
+        fuel_mode: FuelMode::Standard.name().to_string(),
+        engine_mode: EngineMode::Standard.name().to_string(),
+        stints: vec![
+            StrategyStint {
+                compound: Compound::Medium.name().to_string(),
+                end_lap: lap_amount / 2,
+            },
+            StrategyStint {
+                compound: Compound::Hard.name().to_string(),
+                end_lap: lap_amount,
+            },
+        ],
+    }
+}
+
+// Adds a stop halfway through the last stint, if it is long enough to split
+pub fn add_stop(strategy: &mut RaceStrategy) {
+    let start_lap = strategy
+        .stints
+        .iter()
+        .rev()
+        .nth(1)
+        .map_or(0, |stint| stint.end_lap);
+    let Some(last) = strategy.stints.last_mut() else {
+        return;
+    };
+    if last.end_lap - start_lap < 2 {
+        return;
+    }
+    let compound = last.compound.clone();
+    let end_lap = last.end_lap;
+    last.end_lap = start_lap + (end_lap - start_lap) / 2;
+    strategy.stints.push(StrategyStint { compound, end_lap });
+}
+
+// Removes the stop at the end of the given stint, so the stint runs on to the next stop
+pub fn remove_stop(strategy: &mut RaceStrategy, stint_index: usize) {
+    if stint_index + 1 < strategy.stints.len() {
+        let end_lap = strategy.stints.remove(stint_index + 1).end_lap;
+        strategy.stints[stint_index].end_lap = end_lap;
+    }
+}
+
+// Moves the stop at the end of the given stint, keeping every stint at least a lap long
+pub fn move_stop(strategy: &mut RaceStrategy, stint_index: usize, laps: i16) {
+    if stint_index + 1 >= strategy.stints.len() {
+        return;
+    }
+    let previous_end_lap = match stint_index {
+        0 => 0,
+        _ => strategy.stints[stint_index - 1].end_lap,
+    };
+    let next_end_lap = strategy.stints[stint_index + 1].end_lap;
+    let stint = &mut strategy.stints[stint_index];
+    stint.end_lap = (stint.end_lap as i16 + laps)
+        .clamp(previous_end_lap as i16 + 1, next_end_lap as i16 - 1) as u8;
+}
+
+// Estimated race time in seconds the driver gains (negative) or loses with their plan compared
+// to the strategy the team would pick, over races where they finish both ways. The plan is run in
+// place of the one saved for the driver
+pub fn strategy_delta(season_schedule_id: u16, strategy: &RaceStrategy) -> Option<f32> {
+    let mut input = preview_race_input(season_schedule_id);
+    let plan = DriverStrategy::from_race_strategy(strategy, input.circuit.lap_amount)?;
+    input
+        .strategies
+        .retain(|s| s.driver_id != strategy.driver_id);
+    input.strategies.push(plan);
+    estimate_strategy_delta(&input, strategy.driver_id)
+}
+
+pub fn estimate_strategy_delta(input: &RaceInput, driver_id: u16) -> Option<f32> {
+    let mut without_plan = input.clone();
+    without_plan.strategies.retain(|s| s.driver_id != driver_id);
+    let race_time = |input: &RaceInput| -> Option<u32> {
+        let output = RaceEngine::new(input.clone()).run();
+        let session = output.sessions.iter().find(|s| s.session == "Race")?;
+        let result = session
+            .driver_results
+            .iter()
+            .find(|r| r.driver_id == driver_id && r.retired_lap.is_none())?;
+        // a lapped car takes the flag short of the full distance, so its time is scaled up to it
+        let total_ms: u64 = result.laps.iter().map(|l| l.lap_time_ms as u64).sum();
+        Some((total_ms * input.circuit.lap_amount as u64 / result.laps.len() as u64) as u32)
+    };
+
+    let deltas: Vec<f32> = (0..STRATEGY_ESTIMATE_RUNS)
+        .into_par_iter()
+        .filter_map(|run| {
+            let seed = (input.seed as u64 ^ STRATEGY_ESTIMATE_SEED_SALT).wrapping_add(run) as i64;
+            let mut with_plan = input.clone();
+            with_plan.seed = seed;
+            let mut without_plan = without_plan.clone();
+            without_plan.seed = seed;
+            let planned_ms = race_time(&with_plan)?;
+            let default_ms = race_time(&without_plan)?;
+            Some((planned_ms as f32 - default_ms as f32) / 1000.0)
+        })
+        .collect();
+    if deltas.is_empty() {
+        return None;
+    }
+    Some(deltas.iter().sum::<f32>() / deltas.len() as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(stints: &[(&str, u8)]) -> RaceStrategy {
+        RaceStrategy {
+            driver_id: 3,
+            fuel_mode: FuelMode::Rich.name().to_string(),
+            engine_mode: EngineMode::Aggressive.name().to_string(),
+            stints: stints
+                .iter()
+                .map(|(compound, end_lap)| StrategyStint {
+                    compound: compound.to_string(),
+                    end_lap: *end_lap,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn a_valid_plan_is_run_as_saved() {
+        let strategy =
+            DriverStrategy::from_race_strategy(&plan(&[("Soft", 20), ("Hard", 57)]), 57).unwrap();
+        assert_eq!(strategy.driver_id, 3);
+        let stints: Vec<(&str, u8)> = strategy
+            .stints
+            .iter()
+            .map(|s| (s.compound.name(), s.end_lap))
+            .collect();
+        assert_eq!(stints, [("Soft", 20), ("Hard", 57)]);
+        assert!(strategy.fuel_mode == FuelMode::Rich);
+        assert!(strategy.engine_mode == EngineMode::Aggressive);
+    }
+
+    #[test]
+    fn stops_out_of_order_or_past_the_flag_reject_the_plan() {
+        let from = |stints: &[(&str, u8)]| DriverStrategy::from_race_strategy(&plan(stints), 57);
+        assert!(from(&[("Soft", 30), ("Hard", 20), ("Medium", 57)]).is_none());
+        assert!(from(&[("Soft", 20), ("Hard", 20), ("Medium", 57)]).is_none());
+        assert!(from(&[("Soft", 20), ("Hard", 58)]).is_none());
+        assert!(from(&[("Soft", 0), ("Hard", 57)]).is_none());
+        assert!(from(&[]).is_none());
+    }
+
+    #[test]
+    fn unknown_names_reject_the_plan() {
+        assert!(
+            DriverStrategy::from_race_strategy(&plan(&[("Slick", 20), ("Hard", 57)]), 57).is_none()
+        );
+        let mut unknown_mode = plan(&[("Soft", 20), ("Hard", 57)]);
+        unknown_mode.fuel_mode = "Full".to_string();
+        assert!(DriverStrategy::from_race_strategy(&unknown_mode, 57).is_none());
+    }
+}
@@ -0,0 +1,253 @@
+use crate::backend::weather::Condition;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+const PIT_STOP_STATIONARY_MIN: f32 = 2.0; // Fastest tyre change in seconds
+const PIT_STOP_STATIONARY_MAX: f32 = 4.0; // Slowest tyre change in seconds
+const CLIFF_DEGRADATION: f32 = 0.004; // Extra lap time per lap once a tyre is past its cliff
+const STOP_LAP_VARIATION: i16 = 3; // Planned stop laps vary by up to this many laps
+const AVERAGE_TYRE_WEAR: f32 = 5.0; // Circuit tyre wear the compound figures are tuned for
+const TYRE_WEAR_SCALING: f32 = 0.1; // Change in wear rate per step of circuit tyre wear
+
+// Strategies the teams pick between, each uses two dry compounds as the rules require
+const DEFAULT_STRATEGIES: [&[Compound]; 5] = [
+    &[Compound::Medium, Compound::Hard],
+    &[Compound::Hard, Compound::Medium],
+    &[Compound::Soft, Compound::Hard],
+    &[Compound::Soft, Compound::Medium, Compound::Hard],
+    &[Compound::Medium, Compound::Hard, Compound::Soft],
+];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Compound {
+    Soft,
+    Medium,
+    Hard,
+    Intermediate,
+    Wet,
+}
+
+impl Compound {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Compound::Soft => "Soft",
+            Compound::Medium => "Medium",
+            Compound::Hard => "Hard",
+            Compound::Intermediate => "Intermediate",
+            Compound::Wet => "Wet",
+        }
+    }
+
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Compound::Soft => "S",
+            Compound::Medium => "M",
+            Compound::Hard => "H",
+            Compound::Intermediate => "I",
+            Compound::Wet => "W",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Compound> {
+        match name {
+            "Soft" => Some(Compound::Soft),
+            "Medium" => Some(Compound::Medium),
+            "Hard" => Some(Compound::Hard),
+            "Intermediate" => Some(Compound::Intermediate),
+            "Wet" => Some(Compound::Wet),
+            _ => None,
+        }
+    }
+
+    // Lap time lost compared to a new soft tyre on a dry track, as a fraction of the lap time
+    fn pace_offset(&self, condition: Condition) -> f32 {
+        let slick_offset = match condition {
+            Condition::Dry => 0.0,
+            Condition::Damp => 0.1,
+            Condition::Wet => 0.3,
+        };
+        match (self, condition) {
+            (Compound::Soft, _) => slick_offset,
+            (Compound::Medium, _) => slick_offset + 0.006,
+            (Compound::Hard, _) => slick_offset + 0.011,
+            (Compound::Intermediate, Condition::Dry) => 0.08,
+            (Compound::Intermediate, Condition::Damp) => 0.04,
+            (Compound::Intermediate, Condition::Wet) => 0.09,
+            (Compound::Wet, Condition::Dry) => 0.12,
+            (Compound::Wet, Condition::Damp) => 0.07,
+            (Compound::Wet, Condition::Wet) => 0.06,
+        }
+    }
+
+    pub fn is_dry(&self) -> bool {
+        matches!(self, Compound::Soft | Compound::Medium | Compound::Hard)
+    }
+
+    // Lap time lost per lap of wear, as a fraction of the lap time
+    fn degradation(&self) -> f32 {
+        match self {
+            Compound::Soft => 0.0012,
+            Compound::Medium => 0.0007,
+            Compound::Hard => 0.0004,
+            Compound::Intermediate => 0.003,
+            Compound::Wet => 0.004,
+        }
+    }
+
+    // Tyre age at which the grip falls away on a circuit of average tyre wear
+    fn cliff_lap(&self) -> u8 {
+        match self {
+            Compound::Soft => 18,
+            Compound::Medium => 30,
+            Compound::Hard => 42,
+            Compound::Intermediate => 15,
+            Compound::Wet => 12,
+        }
+    }
+}
+
+// How hard a driver is told to lean on their tyres
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TyreMode {
+    Push,
+    Normal,
+    Conserve,
+}
+
+impl TyreMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TyreMode::Push => "Push",
+            TyreMode::Normal => "Normal",
+            TyreMode::Conserve => "Conserve",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<TyreMode> {
+        match name {
+            "Push" => Some(TyreMode::Push),
+            "Normal" => Some(TyreMode::Normal),
+            "Conserve" => Some(TyreMode::Conserve),
+            _ => None,
+        }
+    }
+
+    // Factor to multiply a lap time with
+    pub fn pace_factor(&self) -> f32 {
+        match self {
+            TyreMode::Push => 0.997,
+            TyreMode::Normal => 1.0,
+            TyreMode::Conserve => 1.004,
+        }
+    }
+
+    // Laps of tyre wear per lap driven
+    pub fn wear_factor(&self) -> f32 {
+        match self {
+            TyreMode::Push => 1.5,
+            TyreMode::Normal => 1.0,
+            TyreMode::Conserve => 0.6,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PlannedStint {
+    pub compound: Compound,
+    pub end_lap: u8,
+}
+
+// How much faster than average the tyres wear at a circuit with the given tyre wear (1 to 10)
+fn wear_rate(tyre_wear: u8) -> f32 {
+    1.0 + (tyre_wear as f32 - AVERAGE_TYRE_WEAR) * TYRE_WEAR_SCALING
+}
+
+fn tyre_life(compound: Compound, tyre_wear: u8) -> u8 {
+    (compound.cliff_lap() as f32 / wear_rate(tyre_wear)) as u8
+}
+
+// Factor to multiply a lap time with for a tyre of the given compound and age in laps
+pub fn tyre_factor(compound: Compound, tyre_age: u8, tyre_wear: u8, condition: Condition) -> f32 {
+    let wear = tyre_age as f32 * compound.degradation() * wear_rate(tyre_wear);
+    let cliff = tyre_age.saturating_sub(tyre_life(compound, tyre_wear)) as f32 * CLIFF_DEGRADATION;
+    1.0 + compound.pace_offset(condition) + wear + cliff
+}
+
+pub fn is_worn_out(compound: Compound, tyre_age: u8, tyre_wear: u8) -> bool {
+    tyre_age >= tyre_life(compound, tyre_wear)
+}
+
+// The tyre to race on in the conditions, strategies only plan slicks for a dry track
+pub fn tyre_for_condition(planned: Compound, condition: Condition) -> Compound {
+    match condition {
+        Condition::Dry => planned,
+        Condition::Damp => Compound::Intermediate,
+        Condition::Wet => Compound::Wet,
+    }
+}
+
+pub fn pit_stop_time(pit_lane_time_loss: f32, rng: &mut StdRng) -> f32 {
+    pit_lane_time_loss + rng.gen_range(PIT_STOP_STATIONARY_MIN..PIT_STOP_STATIONARY_MAX)
+}
+
+// Picks one of the default strategies whose tyres last the race distance at this circuit,
+// and spreads the stops over the race by compound life
+pub fn default_strategy(lap_amount: u8, tyre_wear: u8, rng: &mut StdRng) -> Vec<PlannedStint> {
+    let total_life = |compounds: &[Compound]| -> u16 {
+        compounds
+            .iter()
+            .map(|c| tyre_life(*c, tyre_wear) as u16)
+            .sum()
+    };
+    let viable: Vec<&[Compound]> = DEFAULT_STRATEGIES
+        .iter()
+        .copied()
+        .filter(|compounds| total_life(compounds) >= lap_amount as u16)
+        .collect();
+    // the last strategy has the most stops, so it is the fallback when none last the distance
+    let compounds = if viable.is_empty() {
+        DEFAULT_STRATEGIES[DEFAULT_STRATEGIES.len() - 1]
+    } else {
+        viable[rng.gen_range(0..viable.len())]
+    };
+    // every stint needs a lap of its own, a race too short for that drops the last stints
+    let compounds = &compounds[..compounds.len().min(lap_amount.max(1) as usize)];
+    let total_life = total_life(compounds);
+
+    let mut stints = Vec::new();
+    let mut life_used = 0;
+    let mut previous_end_lap = 0;
+    for (index, compound) in compounds.iter().enumerate() {
+        life_used += tyre_life(*compound, tyre_wear) as u16;
+        let end_lap = if index == compounds.len() - 1 {
+            lap_amount
+        } else {
+            let planned = (lap_amount as u16 * life_used / total_life) as i16
+                + rng.gen_range(-STOP_LAP_VARIATION..=STOP_LAP_VARIATION);
+            let remaining_stints = (compounds.len() - 1 - index) as i16;
+            planned.clamp(
+                previous_end_lap as i16 + 1,
+                lap_amount as i16 - remaining_stints,
+            ) as u8
+        };
+        stints.push(PlannedStint {
+            compound: *compound,
+            end_lap,
+        });
+        previous_end_lap = end_lap;
+    }
+    stints
+}
+
+// A single stint on softs or mediums, for races too short to need a stop
+pub fn sprint_strategy(lap_amount: u8, rng: &mut StdRng) -> Vec<PlannedStint> {
+    let compound = if rng.gen_bool(0.5) {
+        Compound::Soft
+    } else {
+        Compound::Medium
+    };
+    vec![PlannedStint {
+        compound,
+        end_lap: lap_amount,
+    }]
+}
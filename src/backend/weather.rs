@@ -0,0 +1,131 @@
+use crate::backend::race::race_seed;
+use crate::database::circuit::get_circuit_by_id;
+use crate::database::race::get_season_schedule_by_id;
+use crate::database::weather::update_race_rain_chance;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const FORECAST_SEED_SALT: u64 = 0x5EA5_0F0E_CA57; // Keeps the forecast rolls apart from the race rolls
+const FORECAST_VARIATION: i16 = 20; // Forecast rain chance differs from the circuit's usual chance by up to this
+const MIN_RAIN_LAPS: u8 = 5;
+const MAX_RAIN_LAPS: u8 = 30;
+const MIN_RAIN_INTENSITY: f32 = 0.3; // A drizzle, only dampens the track
+const MAX_RAIN_INTENSITY: f32 = 1.0; // A downpour
+const WETTING_RATE: f32 = 0.15; // Wetness gained per lap of rain
+const DRYING_RATE: f32 = 0.05; // Wetness lost per lap without rain
+const DAMP_WETNESS: f32 = 0.2; // Track wetness from 0 (dry) to 1 (soaked) at which it turns damp
+const WET_WETNESS: f32 = 0.6; // Track wetness at which it turns wet
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    Dry,
+    Damp,
+    Wet,
+}
+
+impl Condition {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Condition::Dry => "Dry",
+            Condition::Damp => "Damp",
+            Condition::Wet => "Wet",
+        }
+    }
+
+    // How much a damp or wet track brings out the drivers' wet weather skill and their mistakes
+    pub fn severity(&self) -> f32 {
+        match self {
+            Condition::Dry => 0.0,
+            Condition::Damp => 0.5,
+            Condition::Wet => 1.0,
+        }
+    }
+
+    fn from_wetness(wetness: f32) -> Condition {
+        if wetness >= WET_WETNESS {
+            Condition::Wet
+        } else if wetness >= DAMP_WETNESS {
+            Condition::Damp
+        } else {
+            Condition::Dry
+        }
+    }
+}
+
+// Returns the forecast chance of rain in percent, making the forecast the first time it is asked for
+pub fn race_forecast(season_schedule_id: u16) -> u8 {
+    let race = get_season_schedule_by_id(&season_schedule_id).unwrap();
+    if let Some(rain_chance) = race.rain_chance {
+        return rain_chance;
+    }
+
+    let circuit = get_circuit_by_id(&race.circuit_id).unwrap();
+    let mut rng = StdRng::seed_from_u64(race_seed(&race) as u64 ^ FORECAST_SEED_SALT);
+    let rain_chance = (circuit.rain_probability as i16
+        + rng.gen_range(-FORECAST_VARIATION..=FORECAST_VARIATION))
+    .clamp(0, 100) as u8;
+    update_race_rain_chance(season_schedule_id, rain_chance);
+    rain_chance
+}
+
+// Rolls whether the forecast rain comes and returns the track condition for every lap
+pub fn generate_conditions(rain_chance: u8, lap_amount: u8, rng: &mut StdRng) -> Vec<Condition> {
+    if lap_amount == 0 || rng.gen_range(0..100) >= rain_chance {
+        return vec![Condition::Dry; lap_amount as usize];
+    }
+
+    // rain starting on lap 0 has been falling since before the start
+    let rain_start = rng.gen_range(0..lap_amount);
+    let rain_end = rain_start.saturating_add(rng.gen_range(MIN_RAIN_LAPS..=MAX_RAIN_LAPS));
+    let intensity = rng.gen_range(MIN_RAIN_INTENSITY..=MAX_RAIN_INTENSITY);
+
+    let mut wetness = if rain_start == 0 { intensity } else { 0.0 };
+    let mut conditions = Vec::new();
+    for lap_number in 1..=lap_amount {
+        if lap_number >= rain_start && lap_number < rain_end {
+            wetness = (wetness + WETTING_RATE).min(intensity.max(wetness));
+        } else {
+            wetness = (wetness - DRYING_RATE).max(0.0);
+        }
+        conditions.push(Condition::from_wetness(wetness));
+    }
+    conditions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_rain_chance_keeps_every_lap_dry() {
+        for seed in 0..50 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let conditions = generate_conditions(0, 57, &mut rng);
+            assert_eq!(conditions.len(), 57);
+            assert!(conditions.iter().all(|c| *c == Condition::Dry));
+        }
+    }
+
+    #[test]
+    fn a_race_without_laps_has_no_conditions() {
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(generate_conditions(100, 0, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn the_track_gets_wetter_and_dries_a_step_at_a_time() {
+        let mut any_wet = false;
+        for seed in 0..200 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let conditions = generate_conditions(100, 57, &mut rng);
+            assert_eq!(conditions.len(), 57);
+            any_wet |= conditions.contains(&Condition::Wet);
+            // a dry track never turns wet on the next lap, nor a wet one dry
+            for laps in conditions.windows(2) {
+                assert!(laps[0].severity() - laps[1].severity() <= 0.5);
+                assert!(laps[1].severity() - laps[0].severity() <= 0.5);
+            }
+        }
+        assert!(any_wet);
+    }
+}
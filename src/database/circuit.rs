@@ -4,7 +4,8 @@ use crate::model::Circuit;
 pub fn get_circuit_by_id(circuit_id: &u16) -> Option<Circuit> {
     let conn = get_connection().unwrap();
     let mut stmt = conn.prepare(
-        "SELECT name, fk_country_id, city, length_km, lap_amount, image_circuit FROM circuits WHERE id = ?"
+        "SELECT name, fk_country_id, city, length_km, lap_amount, track_type, average_speed_kmh, \
+         overtaking_difficulty, tyre_wear, pit_lane_time_loss, rain_probability, image_circuit FROM circuits WHERE id = ?"
     ).unwrap();
     let row = stmt.query_row([circuit_id], |row| {
         Ok(Circuit {
@@ -13,7 +14,13 @@ pub fn get_circuit_by_id(circuit_id: &u16) -> Option<Circuit> {
             city: row.get(2)?,
             length_km: row.get(3)?,
             lap_amount: row.get(4)?,
-            image_path: row.get(5)?,
+            track_type: row.get(5)?,
+            average_speed_kmh: row.get(6)?,
+            overtaking_difficulty: row.get(7)?,
+            tyre_wear: row.get(8)?,
+            pit_lane_time_loss: row.get(9)?,
+            rain_probability: row.get(10)?,
+            image_path: row.get(11)?,
         })
     });
     match row {
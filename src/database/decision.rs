@@ -0,0 +1,42 @@
+use crate::database::connection::get_connection;
+use crate::model::RaceDecision;
+
+pub fn save_race_decision(season_schedule_id: u16, decision: &RaceDecision) {
+    let conn = get_connection().unwrap();
+    conn.execute(
+        r#"INSERT INTO race_decisions (
+            fk_season_schedule_id, fk_driver_id, lap_number, decision, compound
+        ) VALUES (?, ?, ?, ?, ?)"#,
+        (
+            season_schedule_id,
+            decision.driver_id,
+            decision.lap,
+            &decision.decision,
+            &decision.compound,
+        ),
+    )
+    .unwrap();
+}
+
+pub fn get_race_decisions(race_id: &u16) -> Vec<RaceDecision> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT fk_driver_id, lap_number, decision, compound
+           FROM race_decisions
+           WHERE fk_season_schedule_id = ?
+           ORDER BY id"#,
+        )
+        .unwrap();
+    let decision_iter = stmt
+        .query_map([race_id], |row| {
+            Ok(RaceDecision {
+                driver_id: row.get(0)?,
+                lap: row.get(1)?,
+                decision: row.get(2)?,
+                compound: row.get(3)?,
+            })
+        })
+        .unwrap();
+    decision_iter.filter_map(Result::ok).collect()
+}
@@ -1,11 +1,12 @@
 use crate::database::connection::get_connection;
-use crate::model::{Driver, DriverContract, RaceInfo, SeasonInfo};
+use crate::model::{Driver, DriverContract, DriverRatingChange, RaceInfo, SeasonInfo};
 use std::collections::HashMap;
 
 pub fn get_driver_by_id(id: &u16) -> Option<Driver> {
     let conn = get_connection().unwrap();
     let mut stmt = conn.prepare(
-        r#"SELECT id, first_name, last_name, rating, fk_country_id, date_of_birth, racing_number, image_driver
+        r#"SELECT id, first_name, last_name, pace, racecraft, consistency, tyre_management, wet_skill, starts, experience,
+                  potential, fk_country_id, date_of_birth, racing_number, image_driver
            FROM drivers WHERE id = ?"#
     ).unwrap();
     let row = stmt.query_row([id], |row| {
@@ -13,11 +14,18 @@ pub fn get_driver_by_id(id: &u16) -> Option<Driver> {
             id: row.get(0)?,
             first_name: row.get(1)?,
             last_name: row.get(2)?,
-            rating: row.get(3)?,
-            country_id: row.get(4)?,
-            date_of_birth: row.get(5)?,
-            racing_number: row.get(6)?,
-            image_path: row.get(7)?,
+            pace: row.get(3)?,
+            racecraft: row.get(4)?,
+            consistency: row.get(5)?,
+            tyre_management: row.get(6)?,
+            wet_skill: row.get(7)?,
+            starts: row.get(8)?,
+            experience: row.get(9)?,
+            potential: row.get(10)?,
+            country_id: row.get(11)?,
+            date_of_birth: row.get(12)?,
+            racing_number: row.get(13)?,
+            image_path: row.get(14)?,
         })
     });
     match row {
@@ -29,8 +37,9 @@ pub fn get_driver_by_id(id: &u16) -> Option<Driver> {
 pub fn get_all_drivers() -> Vec<Driver> {
     let conn = get_connection().unwrap();
     let mut stmt = conn.prepare(
-        r#"SELECT id, first_name, last_name, rating, fk_country_id, date_of_birth, racing_number, image_driver
-           FROM drivers"#
+        r#"SELECT id, first_name, last_name, pace, racecraft, consistency, tyre_management, wet_skill, starts, experience,
+                  potential, fk_country_id, date_of_birth, racing_number, image_driver
+           FROM drivers ORDER BY id"#
     ).unwrap();
     let driver_iter = stmt
         .query_map([], |row| {
@@ -38,21 +47,33 @@ pub fn get_all_drivers() -> Vec<Driver> {
                 id: row.get(0)?,
                 first_name: row.get(1)?,
                 last_name: row.get(2)?,
-                rating: row.get(3)?,
-                country_id: row.get(4)?,
-                date_of_birth: row.get(5)?,
-                racing_number: row.get(6)?,
-                image_path: row.get(7)?,
+                pace: row.get(3)?,
+                racecraft: row.get(4)?,
+                consistency: row.get(5)?,
+                tyre_management: row.get(6)?,
+                wet_skill: row.get(7)?,
+                starts: row.get(8)?,
+                experience: row.get(9)?,
+                potential: row.get(10)?,
+                country_id: row.get(11)?,
+                date_of_birth: row.get(12)?,
+                racing_number: row.get(13)?,
+                image_path: row.get(14)?,
             })
         })
         .unwrap();
     driver_iter.filter_map(Result::ok).collect()
 }
 
+// The team the driver is under contract with on the current date
 pub fn get_team_id_by_driver_id(driver_id: &u16) -> Option<u16> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
-        .prepare("SELECT fk_team_id FROM driver_contracts WHERE fk_driver_id = ?")
+        .prepare(
+            r#"SELECT fk_team_id FROM driver_contracts
+           WHERE fk_driver_id = ?
+           AND (SELECT "current_date" FROM game_config) BETWEEN date_begin AND date_end"#,
+        )
         .unwrap();
     let row = stmt.query_row([driver_id], |row| row.get(0));
     match row {
@@ -61,7 +82,7 @@ pub fn get_team_id_by_driver_id(driver_id: &u16) -> Option<u16> {
     }
 }
 
-pub fn get_top_driver_standings(limit: Option<u8>) -> Vec<Vec<String>> {
+pub fn get_top_driver_standings(season_id: &u16, limit: Option<u8>) -> Vec<Vec<String>> {
     let conn = get_connection().unwrap();
     let base_query = r#"
         SELECT 
@@ -69,6 +90,7 @@ pub fn get_top_driver_standings(limit: Option<u8>) -> Vec<Vec<String>> {
             COALESCE(SUM(rdr.points), 0) AS total_points
         FROM drivers d
         LEFT JOIN race_driver_results rdr ON d.id = rdr.fk_driver_id
+            AND rdr.fk_season_schedule_id IN (SELECT id FROM season_schedules WHERE fk_season_id = ?)
         GROUP BY d.id, d.first_name, d.last_name
         ORDER BY total_points DESC
     "#;
@@ -78,7 +100,7 @@ pub fn get_top_driver_standings(limit: Option<u8>) -> Vec<Vec<String>> {
     };
     let mut stmt = conn.prepare(&final_query).unwrap();
     let rows = stmt
-        .query_map([], |row| {
+        .query_map([season_id], |row| {
             Ok(vec![
                 row.get::<_, String>(0)?,
                 row.get::<_, i32>(1)?.to_string(),
@@ -123,7 +145,7 @@ pub fn get_driver_season_info(driver_id: &u16, season_year: &u16) -> Option<Seas
             r#"SELECT 
             ss.grand_prix_name,
             ss.date,
-            rdr.placement,
+            CASE WHEN rdr.session = 'Race' THEN rdr.placement END,
             rdr.points
         FROM season_schedules ss
         JOIN race_driver_results rdr ON ss.id = rdr.fk_season_schedule_id
@@ -198,55 +220,197 @@ pub fn get_driver_season_info(driver_id: &u16, season_year: &u16) -> Option<Seas
     })
 }
 
-pub fn get_driver_data() -> Vec<Vec<String>> {
+// Rows for the driver list keyed by the driver id, the overall rating is left to the caller
+pub fn get_driver_data() -> Vec<(u16, Vec<String>)> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
         .prepare(
             r#"SELECT 
+            d.id,
             d.first_name || ' ' || d.last_name AS driver_name,
             d.racing_number,
-            d.rating,
             c.name AS country,
             t.short_name AS team
         FROM drivers d
         JOIN countries c ON d.fk_country_id = c.id
         LEFT JOIN driver_contracts dc ON dc.fk_driver_id = d.id
-        LEFT JOIN teams t ON dc.fk_team_id = t.id
-        WHERE dc.date_end IS NULL OR dc.date_end > strftime('%s', 'now') * 1000"#,
+            AND (SELECT "current_date" FROM game_config) BETWEEN dc.date_begin AND dc.date_end
+        LEFT JOIN teams t ON dc.fk_team_id = t.id"#,
         )
         .unwrap();
     let driver_iter = stmt
         .query_map([], |row| {
-            Ok(vec![
-                row.get::<_, String>(0)?,
-                row.get::<_, i32>(1)?.to_string(),
-                row.get::<_, i32>(2)?.to_string(),
-                row.get::<_, String>(3)?,
-                row.get::<_, Option<String>>(4)?.unwrap_or_default(),
-            ])
+            Ok((
+                row.get(0)?,
+                vec![
+                    row.get::<_, String>(1)?,
+                    row.get::<_, i32>(2)?.to_string(),
+                    row.get::<_, String>(3)?,
+                    row.get::<_, Option<String>>(4)?.unwrap_or_default(),
+                ],
+            ))
         })
         .unwrap();
     driver_iter.filter_map(Result::ok).collect()
 }
 
-pub fn get_driver_contract(driver_id: &u16) -> Option<DriverContract> {
+const CONTRACT_COLUMNS: &str =
+    "SELECT fk_team_id, date_begin, date_end, monthly_wage, is_number_one, points_bonus";
+
+fn contract_from_row(row: &rusqlite::Row) -> rusqlite::Result<DriverContract> {
+    Ok(DriverContract {
+        team_id: row.get(0)?,
+        date_begin: row.get(1)?,
+        date_end: row.get(2)?,
+        monthly_wage: row.get(3)?,
+        is_number_one: row.get(4)?,
+        points_bonus: row.get(5)?,
+    })
+}
+
+// Every contract of the driver, past, current and signed for the future, in date order
+pub fn get_driver_contracts(driver_id: &u16) -> Vec<DriverContract> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
-        .prepare(
-            r#"SELECT date_begin, date_end, monthly_wage
+        .prepare(&format!(
+            r#"{}
            FROM driver_contracts
-           WHERE fk_driver_id = ?"#,
+           WHERE fk_driver_id = ?
+           ORDER BY date_begin"#,
+            CONTRACT_COLUMNS
+        ))
+        .unwrap();
+    let contract_iter = stmt.query_map([driver_id], contract_from_row).unwrap();
+    contract_iter.filter_map(Result::ok).collect()
+}
+
+// The drivers under contract on the date with the team they race for
+pub fn get_contracted_drivers(date: &str) -> Vec<(u16, u16)> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT fk_driver_id, fk_team_id FROM driver_contracts
+           WHERE ? BETWEEN date_begin AND date_end
+           ORDER BY fk_driver_id"#,
+        )
+        .unwrap();
+    let contract_iter = stmt
+        .query_map([date], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+    contract_iter.filter_map(Result::ok).collect()
+}
+
+pub fn insert_driver_contract(driver_id: u16, contract: &DriverContract) {
+    let conn = get_connection().unwrap();
+    conn.execute(
+        r#"INSERT INTO driver_contracts (
+            fk_driver_id, fk_team_id, date_begin, date_end, monthly_wage, is_number_one,
+            points_bonus
+        ) VALUES (?, ?, ?, ?, ?, ?, ?)"#,
+        (
+            driver_id,
+            contract.team_id,
+            &contract.date_begin,
+            &contract.date_end,
+            contract.monthly_wage,
+            contract.is_number_one,
+            contract.points_bonus,
+        ),
+    )
+    .unwrap();
+}
+
+// Saves the driver's developed attributes together with the change to their overall rating
+pub fn save_driver_development(
+    driver: &Driver,
+    season_schedule_id: u16,
+    phase: &str,
+    rating_before: u8,
+    rating_after: u8,
+) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    tx.execute(
+        r#"UPDATE drivers SET pace = ?, racecraft = ?, consistency = ?, tyre_management = ?,
+            wet_skill = ?, starts = ?, experience = ?
+           WHERE id = ?"#,
+        (
+            driver.pace,
+            driver.racecraft,
+            driver.consistency,
+            driver.tyre_management,
+            driver.wet_skill,
+            driver.starts,
+            driver.experience,
+            driver.id,
+        ),
+    )
+    .unwrap();
+    tx.execute(
+        r#"INSERT INTO driver_rating_history (
+            fk_driver_id, fk_season_schedule_id, phase, rating_before, rating_after
+        ) VALUES (?, ?, ?, ?, ?)"#,
+        (
+            driver.id,
+            season_schedule_id,
+            phase,
+            rating_before,
+            rating_after,
+        ),
+    )
+    .unwrap();
+    tx.commit().unwrap();
+}
+
+// Whether the drivers already developed after the race
+pub fn has_driver_development(season_schedule_id: &u16) -> bool {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT COUNT(*) FROM driver_rating_history WHERE fk_season_schedule_id = ?")
+        .unwrap();
+    let count: u32 = stmt
+        .query_row([season_schedule_id], |row| row.get(0))
+        .unwrap();
+    count > 0
+}
+
+pub fn get_driver_rating_history(driver_id: &u16) -> Vec<DriverRatingChange> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT ss.date, ss.grand_prix_name, h.phase, h.rating_before, h.rating_after
+           FROM driver_rating_history h
+           JOIN season_schedules ss ON ss.id = h.fk_season_schedule_id
+           WHERE h.fk_driver_id = ?
+           ORDER BY ss.date, h.id"#,
         )
         .unwrap();
-    let row = stmt.query_row([driver_id], |row| {
-        Ok(DriverContract {
-            date_begin: row.get(0)?,
-            date_end: row.get(1)?,
-            monthly_wage: row.get(2)?,
+    let change_iter = stmt
+        .query_map([driver_id], |row| {
+            Ok(DriverRatingChange {
+                date: row.get(0)?,
+                grand_prix_name: row.get(1)?,
+                phase: row.get(2)?,
+                rating_before: row.get(3)?,
+                rating_after: row.get(4)?,
+            })
         })
-    });
-    match row {
-        Ok(contract) => Some(contract),
-        Err(_) => None,
-    }
+        .unwrap();
+    change_iter.filter_map(Result::ok).collect()
+}
+
+// Average finishing position in the season's main races, None before the driver's first race
+pub fn get_average_race_placement(driver_id: &u16, season_id: &u16) -> Option<f32> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT AVG(rdr.placement)
+           FROM race_driver_results rdr
+           JOIN season_schedules ss ON ss.id = rdr.fk_season_schedule_id
+           WHERE rdr.fk_driver_id = ? AND ss.fk_season_id = ? AND rdr.session = 'Race'"#,
+        )
+        .unwrap();
+    stmt.query_row((driver_id, season_id), |row| row.get::<_, Option<f64>>(0))
+        .unwrap()
+        .map(|average| average as f32)
 }
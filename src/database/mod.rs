@@ -5,9 +5,20 @@ pub mod circuit;
 pub mod config;
 mod connection;
 pub mod country;
+pub mod decision;
 pub mod driver;
+pub mod news;
+pub mod penalty;
+pub mod points;
+pub mod power_unit;
+pub mod practice;
+pub mod qualifying;
 pub mod race;
+pub mod safety_car;
+pub mod season;
+pub mod strategy;
 pub mod teams;
+pub mod weather;
 
 static GAME_NUMBER: AtomicU16 = AtomicU16::new(0);
 
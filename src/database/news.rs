@@ -0,0 +1,28 @@
+use crate::database::connection::get_connection;
+use crate::model::NewsItem;
+
+pub fn save_news_item(date: &str, message: &str) {
+    let conn = get_connection().unwrap();
+    conn.execute(
+        "INSERT INTO news_items (date, message) VALUES (?, ?)",
+        (date, message),
+    )
+    .unwrap();
+}
+
+// The latest news first
+pub fn get_latest_news(limit: u8) -> Vec<NewsItem> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT date, message FROM news_items ORDER BY date DESC, id DESC LIMIT ?")
+        .unwrap();
+    let news_iter = stmt
+        .query_map([limit], |row| {
+            Ok(NewsItem {
+                date: row.get(0)?,
+                message: row.get(1)?,
+            })
+        })
+        .unwrap();
+    news_iter.filter_map(Result::ok).collect()
+}
@@ -0,0 +1,82 @@
+use crate::database::connection::get_connection;
+use crate::model::Penalty;
+
+pub fn save_penalties(season_schedule_id: u16, session: &str, penalties: &[Penalty]) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    {
+        let mut stmt = tx
+            .prepare(
+                r#"INSERT INTO penalties (
+                fk_season_schedule_id, session, fk_driver_id, kind, value, lap, reason
+            ) VALUES (?, ?, ?, ?, ?, ?, ?)"#,
+            )
+            .unwrap();
+        for penalty in penalties {
+            stmt.execute((
+                season_schedule_id,
+                session,
+                penalty.driver_id,
+                &penalty.kind,
+                penalty.value,
+                penalty.lap,
+                &penalty.reason,
+            ))
+            .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+}
+
+pub fn get_penalties(race_id: &u16, session: &str) -> Vec<Penalty> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT fk_driver_id, kind, value, lap, reason
+           FROM penalties
+           WHERE fk_season_schedule_id = ? AND session = ?
+           ORDER BY lap IS NULL, lap, id"#,
+        )
+        .unwrap();
+    let penalty_iter = stmt
+        .query_map((race_id, session), |row| {
+            Ok(Penalty {
+                driver_id: row.get(0)?,
+                kind: row.get(1)?,
+                value: row.get(2)?,
+                lap: row.get(3)?,
+                reason: row.get(4)?,
+            })
+        })
+        .unwrap();
+    penalty_iter.filter_map(Result::ok).collect()
+}
+
+// Grid penalties handed out at the race weekend before this one, served on this race's grid
+pub fn get_grid_penalties(race_id: &u16) -> Vec<Penalty> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT p.fk_driver_id, p.kind, p.value, p.lap, p.reason
+           FROM penalties p
+           WHERE p.kind = 'Grid Penalty'
+             AND p.fk_season_schedule_id = (
+               SELECT prev.id FROM season_schedules prev, season_schedules ss
+               WHERE ss.id = ? AND prev.date < ss.date
+               ORDER BY prev.date DESC LIMIT 1)
+           ORDER BY p.id"#,
+        )
+        .unwrap();
+    let penalty_iter = stmt
+        .query_map([race_id], |row| {
+            Ok(Penalty {
+                driver_id: row.get(0)?,
+                kind: row.get(1)?,
+                value: row.get(2)?,
+                lap: row.get(3)?,
+                reason: row.get(4)?,
+            })
+        })
+        .unwrap();
+    penalty_iter.filter_map(Result::ok).collect()
+}
@@ -0,0 +1,47 @@
+use crate::database::connection::get_connection;
+use crate::model::PointsSystem;
+
+// Returns the points system for a session of the season, a season without one scores no points
+pub fn get_points_system(season_id: &u16, session: &str) -> PointsSystem {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT id, fastest_lap_points, fastest_lap_max_placement
+           FROM points_systems
+           WHERE fk_season_id = ? AND session = ?"#,
+        )
+        .unwrap();
+    let row = stmt.query_row((season_id, session), |row| {
+        Ok((row.get::<_, u16>(0)?, row.get(1)?, row.get(2)?))
+    });
+    let Ok((points_system_id, fastest_lap_points, fastest_lap_max_placement)) = row else {
+        return PointsSystem {
+            placement_points: Vec::new(),
+            fastest_lap_points: 0,
+            fastest_lap_max_placement: None,
+        };
+    };
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT placement, points FROM points_system_placements WHERE fk_points_system_id = ?",
+        )
+        .unwrap();
+    let points_iter = stmt
+        .query_map([points_system_id], |row| {
+            Ok((row.get::<_, usize>(0)?, row.get::<_, u16>(1)?))
+        })
+        .unwrap();
+    let mut placement_points = Vec::new();
+    for (placement, points) in points_iter.filter_map(Result::ok) {
+        if placement_points.len() < placement {
+            placement_points.resize(placement, 0);
+        }
+        placement_points[placement - 1] = points;
+    }
+    PointsSystem {
+        placement_points,
+        fastest_lap_points,
+        fastest_lap_max_placement,
+    }
+}
@@ -0,0 +1,100 @@
+use crate::database::connection::get_connection;
+use crate::model::PowerUnitComponent;
+
+const COMPONENT_COLUMNS: &str = r#"SELECT c.id, c.fk_driver_id, c.kind, c.number, c.is_fitted,
+            COALESCE(SUM(u.distance_km), 0), COALESCE(MAX(u.failed), 0)
+           FROM power_unit_components c
+           LEFT JOIN power_unit_component_races u ON u.fk_power_unit_component_id = c.id"#;
+
+fn component_from_row(row: &rusqlite::Row) -> rusqlite::Result<PowerUnitComponent> {
+    Ok(PowerUnitComponent {
+        id: row.get(0)?,
+        driver_id: row.get(1)?,
+        kind: row.get(2)?,
+        number: row.get(3)?,
+        is_fitted: row.get(4)?,
+        distance_km: row.get(5)?,
+        failed: row.get(6)?,
+    })
+}
+
+pub fn get_power_unit_components(season_id: &u16, driver_id: &u16) -> Vec<PowerUnitComponent> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(&format!(
+            r#"{}
+           WHERE c.fk_season_id = ? AND c.fk_driver_id = ?
+           GROUP BY c.id
+           ORDER BY c.kind, c.number"#,
+            COMPONENT_COLUMNS
+        ))
+        .unwrap();
+    let component_iter = stmt
+        .query_map((season_id, driver_id), component_from_row)
+        .unwrap();
+    component_iter.filter_map(Result::ok).collect()
+}
+
+// Components of all drivers that are first used at the race
+pub fn get_power_unit_components_for_race(season_schedule_id: &u16) -> Vec<PowerUnitComponent> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(&format!(
+            r#"{}
+           WHERE c.fk_season_schedule_id = ?
+           GROUP BY c.id
+           ORDER BY c.id"#,
+            COMPONENT_COLUMNS
+        ))
+        .unwrap();
+    let component_iter = stmt
+        .query_map([season_schedule_id], component_from_row)
+        .unwrap();
+    component_iter.filter_map(Result::ok).collect()
+}
+
+// Fits a new component and puts the one it replaces in the pool
+pub fn insert_power_unit_component(
+    season_id: u16,
+    driver_id: u16,
+    kind: &str,
+    number: u8,
+    season_schedule_id: u16,
+) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    tx.execute(
+        r#"UPDATE power_unit_components SET is_fitted = 0
+           WHERE fk_season_id = ? AND fk_driver_id = ? AND kind = ?"#,
+        (season_id, driver_id, kind),
+    )
+    .unwrap();
+    tx.execute(
+        r#"INSERT INTO power_unit_components (
+            fk_season_id, fk_driver_id, kind, number, fk_season_schedule_id, is_fitted
+        ) VALUES (?, ?, ?, ?, ?, 1)"#,
+        (season_id, driver_id, kind, number, season_schedule_id),
+    )
+    .unwrap();
+    tx.commit().unwrap();
+}
+
+// Saves the distance each component covered at the race and whether it failed there
+pub fn save_power_unit_usage(season_schedule_id: u16, usage: &[(u16, f32, bool)]) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    {
+        let mut stmt = tx
+            .prepare(
+                r#"INSERT INTO power_unit_component_races (
+                fk_power_unit_component_id, fk_season_schedule_id, distance_km, failed
+            ) VALUES (?, ?, ?, ?)"#,
+            )
+            .unwrap();
+        for (component_id, distance_km, failed) in usage {
+            stmt.execute((component_id, season_schedule_id, distance_km, failed))
+                .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+}
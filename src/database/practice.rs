@@ -0,0 +1,151 @@
+use crate::database::connection::get_connection;
+use crate::model::{PracticeDriverResult, PracticePlan, PracticeResult};
+
+pub fn save_practice_results(
+    season_schedule_id: u16,
+    session: &str,
+    practice_results: &[PracticeDriverResult],
+) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    {
+        let mut stmt = tx
+            .prepare(
+                r#"INSERT INTO practice_results (
+                fk_season_schedule_id, session, fk_driver_id, fk_team_id, position, best_lap_ms,
+                laps, programme, qualifying_setup, race_setup
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
+            )
+            .unwrap();
+        for result in practice_results {
+            stmt.execute((
+                season_schedule_id,
+                session,
+                result.driver_id,
+                result.team_id,
+                result.position,
+                result.best_lap_ms,
+                result.laps,
+                &result.programme,
+                result.qualifying_setup,
+                result.race_setup,
+            ))
+            .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+}
+
+pub fn get_practice_results(race_id: &u16, session: &str) -> Vec<PracticeResult> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT
+            pr.position AS Position,
+            d.racing_number AS DriverNumber,
+            (d.first_name || ' ' || d.last_name) AS DriverName,
+            t.short_name AS Team,
+            pr.best_lap_ms,
+            pr.laps,
+            pr.programme
+        FROM practice_results pr
+        JOIN drivers d ON pr.fk_driver_id = d.id
+        JOIN teams t ON pr.fk_team_id = t.id
+        WHERE pr.fk_season_schedule_id = ? AND pr.session = ?
+        ORDER BY pr.position ASC"#,
+        )
+        .unwrap();
+    let results = stmt
+        .query_map((race_id, session), |row| {
+            Ok(PracticeResult {
+                position: row.get(0)?,
+                driver_number: row.get(1)?,
+                driver_name: row.get(2)?,
+                team: row.get(3)?,
+                best_lap_ms: row.get(4)?,
+                laps: row.get(5)?,
+                programme: row.get(6)?,
+            })
+        })
+        .unwrap();
+    results.filter_map(Result::ok).collect()
+}
+
+// Practice sessions of the race weekend that have been run
+pub fn get_practice_sessions_run(race_id: &u16) -> Vec<String> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT DISTINCT session FROM practice_results
+           WHERE fk_season_schedule_id = ?
+           ORDER BY session"#,
+        )
+        .unwrap();
+    let sessions = stmt.query_map([race_id], |row| row.get(0)).unwrap();
+    sessions.filter_map(Result::ok).collect()
+}
+
+// Setup knowledge (qualifying, race) each driver has after the latest practice session run
+pub fn get_setup_knowledge(race_id: &u16) -> Vec<(u16, f32, f32)> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT fk_driver_id, qualifying_setup, race_setup
+           FROM practice_results
+           WHERE fk_season_schedule_id = ? AND session = (
+             SELECT MAX(session) FROM practice_results WHERE fk_season_schedule_id = ?)"#,
+        )
+        .unwrap();
+    let knowledge = stmt
+        .query_map((race_id, race_id), |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .unwrap();
+    knowledge.filter_map(Result::ok).collect()
+}
+
+pub fn save_practice_plan(season_schedule_id: u16, session: &str, plan: &PracticePlan) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    tx.execute(
+        r#"DELETE FROM practice_plans
+           WHERE fk_season_schedule_id = ? AND session = ? AND fk_driver_id = ?"#,
+        (season_schedule_id, session, plan.driver_id),
+    )
+    .unwrap();
+    tx.execute(
+        r#"INSERT INTO practice_plans (
+            fk_season_schedule_id, session, fk_driver_id, laps, programme
+        ) VALUES (?, ?, ?, ?, ?)"#,
+        (
+            season_schedule_id,
+            session,
+            plan.driver_id,
+            plan.laps,
+            &plan.programme,
+        ),
+    )
+    .unwrap();
+    tx.commit().unwrap();
+}
+
+pub fn get_practice_plans(race_id: &u16, session: &str) -> Vec<PracticePlan> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT fk_driver_id, laps, programme
+           FROM practice_plans
+           WHERE fk_season_schedule_id = ? AND session = ?"#,
+        )
+        .unwrap();
+    let plans = stmt
+        .query_map((race_id, session), |row| {
+            Ok(PracticePlan {
+                driver_id: row.get(0)?,
+                laps: row.get(1)?,
+                programme: row.get(2)?,
+            })
+        })
+        .unwrap();
+    plans.filter_map(Result::ok).collect()
+}
@@ -0,0 +1,69 @@
+use crate::database::connection::get_connection;
+use crate::model::{QualifyingDriverResult, QualifyingResult};
+
+pub fn save_qualifying_results(
+    season_schedule_id: u16,
+    session: &str,
+    qualifying_results: &[QualifyingDriverResult],
+) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    {
+        let mut stmt = tx
+            .prepare(
+                r#"INSERT INTO qualifying_results (
+                fk_season_schedule_id, session, fk_driver_id, fk_team_id, grid_position, q1_time_ms, q2_time_ms, q3_time_ms
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)"#,
+            )
+            .unwrap();
+        for result in qualifying_results {
+            stmt.execute((
+                season_schedule_id,
+                session,
+                result.driver_id,
+                result.team_id,
+                result.grid_position,
+                result.q1_time_ms,
+                result.q2_time_ms,
+                result.q3_time_ms,
+            ))
+            .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+}
+
+pub fn get_qualifying_results(race_id: &u16, session: &str) -> Vec<QualifyingResult> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT 
+            qr.grid_position AS Position,
+            d.racing_number AS DriverNumber,
+            (d.first_name || ' ' || d.last_name) AS DriverName,
+            t.short_name AS Team,
+            qr.q1_time_ms,
+            qr.q2_time_ms,
+            qr.q3_time_ms
+        FROM qualifying_results qr
+        JOIN drivers d ON qr.fk_driver_id = d.id
+        JOIN teams t ON qr.fk_team_id = t.id
+        WHERE qr.fk_season_schedule_id = ? AND qr.session = ?
+        ORDER BY qr.grid_position ASC"#,
+        )
+        .unwrap();
+    let results = stmt
+        .query_map((race_id, session), |row| {
+            Ok(QualifyingResult {
+                position: row.get(0)?,
+                driver_number: row.get(1)?,
+                driver_name: row.get(2)?,
+                team: row.get(3)?,
+                q1_time_ms: row.get(4)?,
+                q2_time_ms: row.get(5)?,
+                q3_time_ms: row.get(6)?,
+            })
+        })
+        .unwrap();
+    results.filter_map(Result::ok).collect()
+}
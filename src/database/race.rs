@@ -1,18 +1,25 @@
 use crate::database::connection::get_connection;
-use crate::model::{Lap, RaceDriverResult, RaceResult, SeasonSchedule};
+use crate::model::{Lap, RaceDriverResult, RaceResult, SeasonSchedule, Stint};
 use std::collections::HashMap;
 
 pub fn get_season_schedule_by_id(season_schedule_id: &u16) -> Option<SeasonSchedule> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
-        .prepare("SELECT id, fk_circuit_id, date, status FROM season_schedules WHERE id = ?")
+        .prepare(
+            "SELECT id, fk_season_id, fk_circuit_id, date, status, seed, rain_chance, is_sprint \
+             FROM season_schedules WHERE id = ?",
+        )
         .unwrap();
     let row = stmt.query_row([season_schedule_id], |row| {
         Ok(SeasonSchedule {
             id: row.get(0)?,
-            circuit_id: row.get(1)?,
-            date: row.get(2)?,
-            status: row.get(3)?,
+            season_id: row.get(1)?,
+            circuit_id: row.get(2)?,
+            date: row.get(3)?,
+            status: row.get(4)?,
+            seed: row.get(5)?,
+            rain_chance: row.get(6)?,
+            is_sprint: row.get(7)?,
         })
     });
     match row {
@@ -21,18 +28,37 @@ pub fn get_season_schedule_by_id(season_schedule_id: &u16) -> Option<SeasonSched
     }
 }
 
-pub fn get_race_id_by_grandprix_name(grand_prix_name: &str) -> Option<u16> {
+// The names repeat every season, so the race is looked up within one
+pub fn get_race_id_by_grandprix_name(grand_prix_name: &str, season_id: &u16) -> Option<u16> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
-        .prepare("SELECT id FROM season_schedules WHERE grand_prix_name = ?")
+        .prepare("SELECT id FROM season_schedules WHERE grand_prix_name = ? AND fk_season_id = ?")
         .unwrap();
-    let row = stmt.query_row([grand_prix_name], |row| row.get(0));
+    let row = stmt.query_row((grand_prix_name, season_id), |row| row.get(0));
     match row {
         Ok(id) => Some(id),
         Err(_) => None,
     }
 }
 
+pub fn get_race_id_by_date(date: &str) -> Option<u16> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT id FROM season_schedules WHERE date = ?")
+        .unwrap();
+    stmt.query_row([date], |row| row.get(0)).ok()
+}
+
+// The races of the season in calendar order
+pub fn get_season_race_ids(season_id: &u16) -> Vec<u16> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT id FROM season_schedules WHERE fk_season_id = ? ORDER BY date")
+        .unwrap();
+    let id_iter = stmt.query_map([season_id], |row| row.get(0)).unwrap();
+    id_iter.filter_map(Result::ok).collect()
+}
+
 pub fn update_race_status(season_schedule_id: u16, status: &str) {
     let conn = get_connection().unwrap();
     let mut stmt = conn
@@ -42,9 +68,60 @@ pub fn update_race_status(season_schedule_id: u16, status: &str) {
         .unwrap();
 }
 
+// Races that are run but whose result the player can still change with orders
+pub fn get_unfinalised_race_ids() -> Vec<u16> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            "SELECT id FROM season_schedules WHERE status = 'Finished' AND is_final = 0 ORDER BY date",
+        )
+        .unwrap();
+    let id_iter = stmt.query_map([], |row| row.get(0)).unwrap();
+    id_iter.filter_map(Result::ok).collect()
+}
+
+pub fn set_race_final(season_schedule_id: u16) {
+    let conn = get_connection().unwrap();
+    conn.execute(
+        "UPDATE season_schedules SET is_final = 1 WHERE id = ?",
+        [season_schedule_id],
+    )
+    .unwrap();
+}
+
+pub fn update_race_seed(season_schedule_id: u16, seed: i64) {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("UPDATE season_schedules SET seed = ? WHERE id = ?")
+        .unwrap();
+    stmt.execute([seed, season_schedule_id as i64]).unwrap();
+}
+
+// Removes everything the simulation saved for the race weekend, so it can be simulated again
+pub fn delete_race_results(season_schedule_id: u16) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    for sql in [
+        r#"DELETE FROM laps WHERE fk_race_driver_result_id IN (
+            SELECT id FROM race_driver_results WHERE fk_season_schedule_id = ?)"#,
+        r#"DELETE FROM stints WHERE fk_race_driver_result_id IN (
+            SELECT id FROM race_driver_results WHERE fk_season_schedule_id = ?)"#,
+        "DELETE FROM race_driver_results WHERE fk_season_schedule_id = ?",
+        "DELETE FROM qualifying_results WHERE fk_season_schedule_id = ?",
+        "DELETE FROM race_weather WHERE fk_season_schedule_id = ?",
+        "DELETE FROM safety_car_periods WHERE fk_season_schedule_id = ?",
+        "DELETE FROM penalties WHERE fk_season_schedule_id = ?",
+        "DELETE FROM power_unit_component_races WHERE fk_season_schedule_id = ?",
+    ] {
+        tx.execute(sql, [season_schedule_id]).unwrap();
+    }
+    tx.commit().unwrap();
+}
+
 pub fn save_driver_results(
     season_schedule_id: u16,
-    driver_results: Vec<(u16, (RaceDriverResult, Vec<Lap>))>,
+    session: &str,
+    driver_results: Vec<RaceDriverResult>,
 ) {
     let mut conn = get_connection().unwrap();
     let tx = conn.transaction().unwrap();
@@ -52,38 +129,61 @@ pub fn save_driver_results(
         let mut stmt_race_driver_results = tx
             .prepare(
                 r#"INSERT INTO race_driver_results (
-                fk_season_schedule_id, fk_driver_id, fk_team_id, placement, points, status
-            ) VALUES (?, ?, ?, ?, ?, ?)"#,
+                fk_season_schedule_id, session, fk_driver_id, fk_team_id, placement, points, status,
+                retired_lap, retirement_reason
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"#,
             )
             .unwrap();
         let mut stmt_laps = tx
             .prepare(
                 r#"INSERT INTO laps (
-                fk_race_driver_result_id, lap_time_ms, lap_number
-            ) VALUES (?, ?, ?)"#,
+                fk_race_driver_result_id, lap_time_ms, lap_number, position
+            ) VALUES (?, ?, ?, ?)"#,
             )
             .unwrap();
-        for (_driver_id, (race_driver_result, laps)) in driver_results {
+        let mut stmt_stints = tx
+            .prepare(
+                r#"INSERT INTO stints (
+                fk_race_driver_result_id, stint_number, compound, start_lap, end_lap
+            ) VALUES (?, ?, ?, ?, ?)"#,
+            )
+            .unwrap();
+        for race_driver_result in driver_results {
             stmt_race_driver_results
-                .execute([
-                    &season_schedule_id.to_string(),
-                    &race_driver_result.driver_id.to_string(),
-                    &race_driver_result.team_id.to_string(),
-                    &race_driver_result.placement.to_string(),
-                    &race_driver_result.points.to_string(),
+                .execute((
+                    season_schedule_id,
+                    session,
+                    race_driver_result.driver_id,
+                    race_driver_result.team_id,
+                    race_driver_result.placement,
+                    race_driver_result.points,
                     &race_driver_result.status,
-                ])
+                    race_driver_result.retired_lap,
+                    &race_driver_result.retirement_reason,
+                ))
                 .unwrap();
             let race_driver_result_id = tx.last_insert_rowid();
-            for lap in laps {
+            for lap in race_driver_result.laps {
                 stmt_laps
                     .execute([
                         &race_driver_result_id.to_string(),
                         &lap.lap_time_ms.to_string(),
                         &lap.lap_number.to_string(),
+                        &lap.position.to_string(),
                     ])
                     .unwrap();
             }
+            for stint in race_driver_result.stints {
+                stmt_stints
+                    .execute((
+                        race_driver_result_id,
+                        stint.stint_number,
+                        &stint.compound,
+                        stint.start_lap,
+                        stint.end_lap,
+                    ))
+                    .unwrap();
+            }
         }
     }
     tx.commit().unwrap();
@@ -93,7 +193,7 @@ pub fn get_next_race() -> Option<SeasonSchedule> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
         .prepare(
-            r#"SELECT id, fk_circuit_id, date, status
+            r#"SELECT id, fk_season_id, fk_circuit_id, date, status, seed, rain_chance, is_sprint
            FROM season_schedules
            WHERE status = 'Upcoming'
            ORDER BY date ASC LIMIT 1"#,
@@ -102,9 +202,13 @@ pub fn get_next_race() -> Option<SeasonSchedule> {
     let row = stmt.query_row([], |row| {
         Ok(SeasonSchedule {
             id: row.get(0)?,
-            circuit_id: row.get(1)?,
-            date: row.get(2)?,
-            status: row.get(3)?,
+            season_id: row.get(1)?,
+            circuit_id: row.get(2)?,
+            date: row.get(3)?,
+            status: row.get(4)?,
+            seed: row.get(5)?,
+            rain_chance: row.get(6)?,
+            is_sprint: row.get(7)?,
         })
     });
     match row {
@@ -113,7 +217,7 @@ pub fn get_next_race() -> Option<SeasonSchedule> {
     }
 }
 
-pub fn get_race_results(race_id: &u16) -> Vec<RaceResult> {
+pub fn get_race_results(race_id: &u16, session: &str) -> Vec<RaceResult> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
         .prepare(
@@ -123,32 +227,88 @@ pub fn get_race_results(race_id: &u16) -> Vec<RaceResult> {
             (d.first_name || ' ' || d.last_name) AS DriverName,
             t.short_name AS Team,
             rdr.points AS Points,
-            COALESCE(SUM(l.lap_time_ms), 0) AS TotalTime_ms
+            COALESCE(SUM(l.lap_time_ms), 0) AS TotalTime_ms,
+            rdr.status,
+            rdr.retired_lap,
+            rdr.retirement_reason,
+            rdr.id,
+            qr.grid_position,
+            (SELECT COALESCE(SUM(p.value), 0) FROM penalties p
+             WHERE p.fk_season_schedule_id = rdr.fk_season_schedule_id AND p.session = rdr.session
+               AND p.fk_driver_id = rdr.fk_driver_id AND p.kind = 'Time Penalty') AS PenaltySeconds
         FROM race_driver_results rdr
         JOIN drivers d ON rdr.fk_driver_id = d.id
         JOIN teams t ON rdr.fk_team_id = t.id
         LEFT JOIN laps l ON l.fk_race_driver_result_id = rdr.id
-        WHERE rdr.fk_season_schedule_id = ?
+        LEFT JOIN qualifying_results qr ON qr.fk_season_schedule_id = rdr.fk_season_schedule_id
+            AND qr.fk_driver_id = rdr.fk_driver_id AND qr.session = rdr.session
+        WHERE rdr.fk_season_schedule_id = ? AND rdr.session = ?
         GROUP BY rdr.id, d.id, t.id
         ORDER BY rdr.placement ASC"#,
         )
         .unwrap();
+    let mut stint_stmt = conn
+        .prepare(
+            r#"SELECT stint_number, compound, start_lap, end_lap
+           FROM stints
+           WHERE fk_race_driver_result_id = ?
+           ORDER BY stint_number"#,
+        )
+        .unwrap();
+    let mut lap_stmt = conn
+        .prepare(
+            r#"SELECT lap_time_ms, lap_number, position
+           FROM laps
+           WHERE fk_race_driver_result_id = ?
+           ORDER BY lap_number"#,
+        )
+        .unwrap();
     let results = stmt
-        .query_map([race_id], |row| {
+        .query_map((race_id, session), |row| {
+            let race_driver_result_id: i64 = row.get(9)?;
+            let stints = stint_stmt
+                .query_map([race_driver_result_id], |row| {
+                    Ok(Stint {
+                        stint_number: row.get(0)?,
+                        compound: row.get(1)?,
+                        start_lap: row.get(2)?,
+                        end_lap: row.get(3)?,
+                    })
+                })?
+                .filter_map(Result::ok)
+                .collect();
+            let laps = lap_stmt
+                .query_map([race_driver_result_id], |row| {
+                    Ok(Lap {
+                        lap_time_ms: row.get(0)?,
+                        lap_number: row.get(1)?,
+                        position: row.get(2)?,
+                    })
+                })?
+                .filter_map(Result::ok)
+                .collect();
+            let penalty_seconds: u32 = row.get(11)?;
             Ok(RaceResult {
                 position: row.get(0)?,
+                grid_position: row.get(10)?,
                 driver_number: row.get(1)?,
                 driver_name: row.get(2)?,
                 team: row.get(3)?,
                 points: row.get(4)?,
-                total_time_ms: row.get(5)?,
+                total_time_ms: row.get::<_, u32>(5)? + penalty_seconds * 1000,
+                penalty_seconds,
+                status: row.get(6)?,
+                retired_lap: row.get(7)?,
+                retirement_reason: row.get(8)?,
+                laps,
+                stints,
             })
         })
         .unwrap();
     results.filter_map(Result::ok).collect()
 }
 
-pub fn get_race_list() -> Vec<Vec<String>> {
+pub fn get_race_list(season_id: &u16) -> Vec<Vec<String>> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
         .prepare("SELECT selected_team FROM game_config WHERE id = 1")
@@ -166,11 +326,13 @@ pub fn get_race_list() -> Vec<Vec<String>> {
             d.first_name || ' ' || d.last_name AS winner_name
         FROM season_schedules ss
         LEFT JOIN race_driver_results rdr ON ss.id = rdr.fk_season_schedule_id AND rdr.placement = 1
+            AND rdr.session = 'Race'
         LEFT JOIN drivers d ON rdr.fk_driver_id = d.id
+        WHERE ss.fk_season_id = ?
         ORDER BY ss.date"#,
         )
         .unwrap();
-    let race_rows = match race_stmt.query_map([], |row| {
+    let race_rows = match race_stmt.query_map([season_id], |row| {
         Ok((
             row.get::<_, String>(0)?,
             row.get::<_, i32>(1)?,
@@ -188,11 +350,11 @@ pub fn get_race_list() -> Vec<Vec<String>> {
             rdr.placement
         FROM season_schedules ss
         JOIN race_driver_results rdr ON ss.id = rdr.fk_season_schedule_id
-        WHERE rdr.fk_team_id = ?
+        WHERE ss.fk_season_id = ? AND rdr.fk_team_id = ? AND rdr.session = 'Race'
         ORDER BY ss.date, rdr.placement"#,
         )
         .unwrap();
-    let team_rows = match team_stmt.query_map([selected_team_id], |row| {
+    let team_rows = match team_stmt.query_map((season_id, selected_team_id), |row| {
         Ok((row.get::<_, i32>(0)?, row.get::<_, Option<i32>>(1)?))
     }) {
         Ok(rows) => rows,
@@ -247,7 +409,7 @@ pub fn get_race_list() -> Vec<Vec<String>> {
     race_list
 }
 
-pub fn get_race_schedule_info() -> Vec<Vec<String>> {
+pub fn get_race_schedule_info(season_id: &u16) -> Vec<Vec<String>> {
     let conn = get_connection().unwrap();
     let mut stmt = conn.prepare(
         r#"SELECT 
@@ -262,15 +424,19 @@ pub fn get_race_schedule_info() -> Vec<Vec<String>> {
         JOIN circuits ci ON ss.fk_circuit_id = ci.id
         JOIN countries c ON ci.fk_country_id = c.id
         LEFT JOIN race_driver_results rdr1 ON ss.id = rdr1.fk_season_schedule_id AND rdr1.placement = 1
+            AND rdr1.session = 'Race'
         LEFT JOIN drivers d1 ON rdr1.fk_driver_id = d1.id
         LEFT JOIN race_driver_results rdr2 ON ss.id = rdr2.fk_season_schedule_id AND rdr2.placement = 2
+            AND rdr2.session = 'Race'
         LEFT JOIN drivers d2 ON rdr2.fk_driver_id = d2.id
         LEFT JOIN race_driver_results rdr3 ON ss.id = rdr3.fk_season_schedule_id AND rdr3.placement = 3
+            AND rdr3.session = 'Race'
         LEFT JOIN drivers d3 ON rdr3.fk_driver_id = d3.id
+        WHERE ss.fk_season_id = ?
         ORDER BY CASE WHEN d1.first_name IS NOT NULL THEN 0 ELSE 1 END, ss.date"#
     ).unwrap();
     let rows = stmt
-        .query_map([], |row| {
+        .query_map([season_id], |row| {
             Ok(vec![
                 row.get::<_, String>(0)?,
                 row.get::<_, String>(1)?,
@@ -0,0 +1,55 @@
+use crate::database::connection::get_connection;
+use crate::model::SafetyCarPeriod;
+
+pub fn save_safety_car_periods(
+    season_schedule_id: u16,
+    session: &str,
+    periods: &[SafetyCarPeriod],
+) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    {
+        let mut stmt = tx
+            .prepare(
+                r#"INSERT INTO safety_car_periods (
+                fk_season_schedule_id, session, kind, start_lap, end_lap, reason
+            ) VALUES (?, ?, ?, ?, ?, ?)"#,
+            )
+            .unwrap();
+        for period in periods {
+            stmt.execute((
+                season_schedule_id,
+                session,
+                &period.kind,
+                period.start_lap,
+                period.end_lap,
+                &period.reason,
+            ))
+            .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+}
+
+pub fn get_safety_car_periods(race_id: &u16, session: &str) -> Vec<SafetyCarPeriod> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT kind, start_lap, end_lap, reason
+           FROM safety_car_periods
+           WHERE fk_season_schedule_id = ? AND session = ?
+           ORDER BY start_lap"#,
+        )
+        .unwrap();
+    let period_iter = stmt
+        .query_map((race_id, session), |row| {
+            Ok(SafetyCarPeriod {
+                kind: row.get(0)?,
+                start_lap: row.get(1)?,
+                end_lap: row.get(2)?,
+                reason: row.get(3)?,
+            })
+        })
+        .unwrap();
+    period_iter.filter_map(Result::ok).collect()
+}
@@ -0,0 +1,157 @@
+use crate::database::connection::get_connection;
+use crate::model::Season;
+
+// The season of the year the game is in
+pub fn get_current_season() -> Option<Season> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT id, year FROM seasons
+           WHERE year = CAST(strftime('%Y', (SELECT "current_date" FROM game_config)) AS INTEGER)"#,
+        )
+        .unwrap();
+    stmt.query_row([], |row| {
+        Ok(Season {
+            id: row.get(0)?,
+            year: row.get(1)?,
+        })
+    })
+    .ok()
+}
+
+// Creates the season with the points systems of the previous one and its races on the new
+// dates, given by the id of the race they follow on from
+pub fn insert_season(year: u16, previous_season_id: u16, race_dates: &[(u16, String)]) -> u16 {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    tx.execute("INSERT INTO seasons (year) VALUES (?)", [year])
+        .unwrap();
+    let season_id = tx.last_insert_rowid() as u16;
+
+    let points_system_ids: Vec<u16> = {
+        let mut stmt = tx
+            .prepare("SELECT id FROM points_systems WHERE fk_season_id = ?")
+            .unwrap();
+        let id_iter = stmt
+            .query_map([previous_season_id], |row| row.get(0))
+            .unwrap();
+        id_iter.filter_map(Result::ok).collect()
+    };
+    for points_system_id in points_system_ids {
+        tx.execute(
+            r#"INSERT INTO points_systems
+               (fk_season_id, session, fastest_lap_points, fastest_lap_max_placement)
+           SELECT ?, session, fastest_lap_points, fastest_lap_max_placement
+           FROM points_systems WHERE id = ?"#,
+            (season_id, points_system_id),
+        )
+        .unwrap();
+        let new_points_system_id = tx.last_insert_rowid();
+        tx.execute(
+            r#"INSERT INTO points_system_placements (fk_points_system_id, placement, points)
+           SELECT ?, placement, points FROM points_system_placements
+           WHERE fk_points_system_id = ?"#,
+            (new_points_system_id, points_system_id),
+        )
+        .unwrap();
+    }
+
+    for (season_schedule_id, date) in race_dates {
+        tx.execute(
+            r#"INSERT INTO season_schedules
+               (fk_season_id, fk_circuit_id, date, status, grand_prix_name, is_sprint)
+           SELECT ?, fk_circuit_id, ?, 'Upcoming', grand_prix_name, is_sprint
+           FROM season_schedules WHERE id = ?"#,
+            (season_id, date, season_schedule_id),
+        )
+        .unwrap();
+    }
+    tx.commit().unwrap();
+    season_id
+}
+
+pub fn has_final_standings(season_id: &u16) -> bool {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT COUNT(*) FROM final_driver_standings WHERE fk_season_id = ?")
+        .unwrap();
+    let count: u16 = stmt.query_row([season_id], |row| row.get(0)).unwrap();
+    count > 0
+}
+
+// Keeps the championship tables of the season, ties are decided by the race wins
+pub fn save_final_standings(season_id: &u16) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    tx.execute(
+        r#"INSERT INTO final_driver_standings
+           (fk_season_id, fk_driver_id, fk_team_id, position, points, wins)
+       SELECT
+           ?1,
+           rdr.fk_driver_id,
+           (SELECT last.fk_team_id FROM race_driver_results last
+            JOIN season_schedules last_ss ON last.fk_season_schedule_id = last_ss.id
+            WHERE last.fk_driver_id = rdr.fk_driver_id AND last_ss.fk_season_id = ?1
+            ORDER BY last_ss.date DESC LIMIT 1),
+           ROW_NUMBER() OVER (ORDER BY SUM(rdr.points) DESC,
+               SUM(CASE WHEN rdr.session = 'Race' AND rdr.placement = 1 THEN 1 ELSE 0 END) DESC),
+           SUM(rdr.points),
+           SUM(CASE WHEN rdr.session = 'Race' AND rdr.placement = 1 THEN 1 ELSE 0 END)
+       FROM race_driver_results rdr
+       JOIN season_schedules ss ON rdr.fk_season_schedule_id = ss.id
+       WHERE ss.fk_season_id = ?1
+       GROUP BY rdr.fk_driver_id"#,
+        [season_id],
+    )
+    .unwrap();
+    tx.execute(
+        r#"INSERT INTO final_team_standings (fk_season_id, fk_team_id, position, points, wins)
+       SELECT
+           ?1,
+           rdr.fk_team_id,
+           ROW_NUMBER() OVER (ORDER BY SUM(rdr.points) DESC,
+               SUM(CASE WHEN rdr.session = 'Race' AND rdr.placement = 1 THEN 1 ELSE 0 END) DESC),
+           SUM(rdr.points),
+           SUM(CASE WHEN rdr.session = 'Race' AND rdr.placement = 1 THEN 1 ELSE 0 END)
+       FROM race_driver_results rdr
+       JOIN season_schedules ss ON rdr.fk_season_schedule_id = ss.id
+       WHERE ss.fk_season_id = ?1
+       GROUP BY rdr.fk_team_id"#,
+        [season_id],
+    )
+    .unwrap();
+    tx.commit().unwrap();
+}
+
+// The drivers' and teams' champion of every finished season, the latest first
+pub fn get_champions() -> Vec<Vec<String>> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT
+            s.year,
+            d.first_name || ' ' || d.last_name AS driver_name,
+            fds.points,
+            t.short_name,
+            fts.points
+        FROM seasons s
+        JOIN final_driver_standings fds ON fds.fk_season_id = s.id AND fds.position = 1
+        JOIN drivers d ON fds.fk_driver_id = d.id
+        JOIN final_team_standings fts ON fts.fk_season_id = s.id AND fts.position = 1
+        JOIN teams t ON fts.fk_team_id = t.id
+        ORDER BY s.year DESC"#,
+        )
+        .unwrap();
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(vec![
+                row.get::<_, u16>(0)?.to_string(),
+                row.get::<_, String>(1)?,
+                row.get::<_, u16>(2)?.to_string(),
+                row.get::<_, String>(3)?,
+                row.get::<_, u16>(4)?.to_string(),
+            ])
+        })
+        .unwrap();
+    rows.filter_map(Result::ok).collect()
+}
@@ -0,0 +1,94 @@
+use crate::database::connection::get_connection;
+use crate::model::{RaceStrategy, StrategyStint};
+
+// Replaces the driver's plan for the race
+pub fn save_race_strategy(season_schedule_id: u16, strategy: &RaceStrategy) {
+    delete_race_strategy(season_schedule_id, strategy.driver_id);
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    {
+        tx.execute(
+            r#"INSERT INTO race_strategies (
+                fk_season_schedule_id, fk_driver_id, fuel_mode, engine_mode
+            ) VALUES (?, ?, ?, ?)"#,
+            (
+                season_schedule_id,
+                strategy.driver_id,
+                &strategy.fuel_mode,
+                &strategy.engine_mode,
+            ),
+        )
+        .unwrap();
+        let race_strategy_id = tx.last_insert_rowid();
+        let mut stmt = tx
+            .prepare(
+                r#"INSERT INTO race_strategy_stints (
+                fk_race_strategy_id, stint_number, compound, end_lap
+            ) VALUES (?, ?, ?, ?)"#,
+            )
+            .unwrap();
+        for (index, stint) in strategy.stints.iter().enumerate() {
+            stmt.execute((race_strategy_id, index + 1, &stint.compound, stint.end_lap))
+                .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+}
+
+pub fn delete_race_strategy(season_schedule_id: u16, driver_id: u16) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    tx.execute(
+        r#"DELETE FROM race_strategy_stints WHERE fk_race_strategy_id IN (
+            SELECT id FROM race_strategies WHERE fk_season_schedule_id = ? AND fk_driver_id = ?)"#,
+        [season_schedule_id, driver_id],
+    )
+    .unwrap();
+    tx.execute(
+        "DELETE FROM race_strategies WHERE fk_season_schedule_id = ? AND fk_driver_id = ?",
+        [season_schedule_id, driver_id],
+    )
+    .unwrap();
+    tx.commit().unwrap();
+}
+
+pub fn get_race_strategies(race_id: &u16) -> Vec<RaceStrategy> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT id, fk_driver_id, fuel_mode, engine_mode
+           FROM race_strategies
+           WHERE fk_season_schedule_id = ?
+           ORDER BY fk_driver_id"#,
+        )
+        .unwrap();
+    let mut stint_stmt = conn
+        .prepare(
+            r#"SELECT compound, end_lap
+           FROM race_strategy_stints
+           WHERE fk_race_strategy_id = ?
+           ORDER BY stint_number"#,
+        )
+        .unwrap();
+    let strategy_iter = stmt
+        .query_map([race_id], |row| {
+            let race_strategy_id: i64 = row.get(0)?;
+            let stints = stint_stmt
+                .query_map([race_strategy_id], |row| {
+                    Ok(StrategyStint {
+                        compound: row.get(0)?,
+                        end_lap: row.get(1)?,
+                    })
+                })?
+                .filter_map(Result::ok)
+                .collect();
+            Ok(RaceStrategy {
+                driver_id: row.get(1)?,
+                fuel_mode: row.get(2)?,
+                engine_mode: row.get(3)?,
+                stints,
+            })
+        })
+        .unwrap();
+    strategy_iter.filter_map(Result::ok).collect()
+}
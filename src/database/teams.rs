@@ -1,5 +1,5 @@
 use crate::database::connection::get_connection;
-use crate::model::{RaceInfo, SeasonInfo, Team, TeamBase};
+use crate::model::{CarPerformance, RaceInfo, SeasonInfo, Team, TeamBase, TeamReliability};
 use std::collections::HashMap;
 
 pub fn get_all_teams() -> Vec<(String, String, Vec<(String, String)>)> {
@@ -10,6 +10,7 @@ pub fn get_all_teams() -> Vec<(String, String, Vec<(String, String)>)> {
            FROM teams t 
            JOIN driver_contracts dc ON t.id = dc.fk_team_id
            JOIN drivers d ON dc.fk_driver_id = d.id 
+           WHERE (SELECT "current_date" FROM game_config) BETWEEN dc.date_begin AND dc.date_end
            ORDER BY t.short_name, d.last_name"#,
         )
         .unwrap();
@@ -69,7 +70,8 @@ pub fn get_selected_team(game_number: &str) -> Option<String> {
     }
 }
 
-pub fn get_own_team_standing() -> Option<(String, Vec<String>, u16)> {
+// The player's team with its drivers and their points in the season
+pub fn get_own_team_standing(season_id: &u16) -> Option<(String, Vec<String>, u16)> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
         .prepare(
@@ -82,13 +84,14 @@ pub fn get_own_team_standing() -> Option<(String, Vec<String>, u16)> {
         LEFT JOIN driver_contracts dc ON t.id = dc.fk_team_id
         LEFT JOIN drivers d ON dc.fk_driver_id = d.id
         LEFT JOIN race_driver_results rdr ON d.id = rdr.fk_driver_id
-        WHERE dc.date_end > strftime('%s', 'now') * 1000
+            AND rdr.fk_team_id = t.id AND rdr.fk_season_schedule_id IN (SELECT id FROM season_schedules WHERE fk_season_id = ?)
+        WHERE c."current_date" BETWEEN dc.date_begin AND dc.date_end
         GROUP BY t.short_name, d.id
         ORDER BY total_points DESC"#,
         )
         .unwrap();
     let rows = stmt
-        .query_map([], |row| {
+        .query_map([season_id], |row| {
             Ok((
                 row.get::<_, String>(0)?,
                 row.get::<_, String>(1)?,
@@ -115,13 +118,14 @@ pub fn get_own_team_standing() -> Option<(String, Vec<String>, u16)> {
     }
 }
 
-pub fn get_top_teams_standings(limit: Option<u8>) -> Vec<Vec<String>> {
+pub fn get_top_teams_standings(season_id: &u16, limit: Option<u8>) -> Vec<Vec<String>> {
     let conn = get_connection().unwrap();
     let base_query = r#"SELECT 
         t.short_name,
         COALESCE(SUM(rdr.points), 0) AS total_points
     FROM teams t
     LEFT JOIN race_driver_results rdr ON t.id = rdr.fk_team_id
+        AND rdr.fk_season_schedule_id IN (SELECT id FROM season_schedules WHERE fk_season_id = ?)
     GROUP BY t.id, t.short_name
     ORDER BY total_points DESC"#;
     let final_query = match limit {
@@ -130,7 +134,7 @@ pub fn get_top_teams_standings(limit: Option<u8>) -> Vec<Vec<String>> {
     };
     let mut stmt = conn.prepare(&final_query).unwrap();
     let rows = stmt
-        .query_map([], |row| {
+        .query_map([season_id], |row| {
             Ok(vec![
                 row.get::<_, String>(0)?,
                 row.get::<_, u16>(1)?.to_string(),
@@ -214,7 +218,7 @@ pub fn get_team_season_info(team_id: &u16, season_year: &u16) -> Option<SeasonIn
             r#"SELECT 
             ss.grand_prix_name,
             ss.date,
-            rdr.placement,
+            CASE WHEN rdr.session = 'Race' THEN rdr.placement END,
             rdr.points
         FROM season_schedules ss
         JOIN race_driver_results rdr ON ss.id = rdr.fk_season_schedule_id
@@ -289,7 +293,7 @@ pub fn get_team_season_info(team_id: &u16, season_year: &u16) -> Option<SeasonIn
     })
 }
 
-pub fn get_team_data() -> Vec<Vec<String>> {
+pub fn get_team_data(season_id: &u16) -> Vec<Vec<String>> {
     let conn = get_connection().unwrap();
     let mut stmt = conn
         .prepare(
@@ -299,14 +303,14 @@ pub fn get_team_data() -> Vec<Vec<String>> {
             COALESCE((
                 SELECT SUM(rdr.points)
                 FROM race_driver_results rdr
-                WHERE rdr.fk_team_id = t.id
+                WHERE rdr.fk_team_id = t.id AND rdr.fk_season_schedule_id IN (SELECT id FROM season_schedules WHERE fk_season_id = ?)
             ), 0) AS total_points,
             (
                 SELECT GROUP_CONCAT(d2.first_name || ' ' || d2.last_name, ',')
                 FROM driver_contracts dc2
                 JOIN drivers d2 ON dc2.fk_driver_id = d2.id
                 WHERE dc2.fk_team_id = t.id
-                AND (dc2.date_end IS NULL OR dc2.date_end > strftime('%s', 'now') * 1000)
+                AND (SELECT "current_date" FROM game_config) BETWEEN dc2.date_begin AND dc2.date_end
                 ORDER BY d2.last_name
             ) AS drivers
         FROM teams t
@@ -315,7 +319,7 @@ pub fn get_team_data() -> Vec<Vec<String>> {
         )
         .unwrap();
     let team_iter = stmt
-        .query_map([], |row| {
+        .query_map([season_id], |row| {
             let drivers: Option<String> = row.get(3)?;
             Ok(vec![
                 row.get::<_, String>(0)?,
@@ -358,3 +362,82 @@ pub fn get_team_base_by_team_id(team_id: &u16) -> Option<TeamBase> {
         Err(_) => None,
     }
 }
+
+// A team whose power unit is not in the list of suppliers counts it as reliable as its chassis
+pub fn get_team_reliability(team_id: &u16) -> Option<TeamReliability> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT t.reliability, COALESCE(pu.reliability, t.reliability)
+           FROM teams t
+           LEFT JOIN power_units pu ON pu.name = t.power_unit
+           WHERE t.id = ?"#,
+        )
+        .unwrap();
+    let row = stmt.query_row([team_id], |row| {
+        Ok(TeamReliability {
+            chassis: row.get(0)?,
+            power_unit: row.get(1)?,
+        })
+    });
+    row.ok()
+}
+
+pub fn get_car_performance(team_id: &u16) -> Option<CarPerformance> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT aero, power, mechanical_grip, reliability FROM teams WHERE id = ?")
+        .unwrap();
+    let row = stmt.query_row([team_id], |row| {
+        Ok(CarPerformance {
+            aero: row.get(0)?,
+            power: row.get(1)?,
+            mechanical_grip: row.get(2)?,
+            reliability: row.get(3)?,
+        })
+    });
+    row.ok()
+}
+
+pub fn get_team_facilities(team_id: &u16) -> Option<u8> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT facilities FROM teams WHERE id = ?")
+        .unwrap();
+    stmt.query_row([team_id], |row| row.get(0)).ok()
+}
+
+pub fn get_team_reputation(team_id: &u16) -> Option<u8> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT reputation FROM teams WHERE id = ?")
+        .unwrap();
+    stmt.query_row([team_id], |row| row.get(0)).ok()
+}
+
+// Drivers under contract with the team on the date
+pub fn get_team_contract_count(team_id: &u16, date: &str) -> u8 {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT COUNT(*) FROM driver_contracts
+           WHERE fk_team_id = ? AND ? BETWEEN date_begin AND date_end"#,
+        )
+        .unwrap();
+    stmt.query_row((team_id, date), |row| row.get(0)).unwrap()
+}
+
+pub fn get_selected_team_id() -> Option<u16> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("SELECT selected_team FROM game_config WHERE id = 1")
+        .unwrap();
+    stmt.query_row([], |row| row.get(0)).ok().flatten()
+}
+
+pub fn get_all_team_ids() -> Vec<u16> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn.prepare("SELECT id FROM teams ORDER BY id").unwrap();
+    let team_iter = stmt.query_map([], |row| row.get(0)).unwrap();
+    team_iter.filter_map(Result::ok).collect()
+}
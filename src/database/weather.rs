@@ -0,0 +1,44 @@
+use crate::database::connection::get_connection;
+
+pub fn update_race_rain_chance(season_schedule_id: u16, rain_chance: u8) {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare("UPDATE season_schedules SET rain_chance = ? WHERE id = ?")
+        .unwrap();
+    stmt.execute((rain_chance, season_schedule_id)).unwrap();
+}
+
+// Stores the track condition of every lap, the first condition is for lap 1
+pub fn save_race_weather(season_schedule_id: u16, session: &str, conditions: &[&str]) {
+    let mut conn = get_connection().unwrap();
+    let tx = conn.transaction().unwrap();
+    {
+        let mut stmt = tx
+            .prepare(
+                r#"INSERT INTO race_weather (
+                fk_season_schedule_id, session, lap_number, condition
+            ) VALUES (?, ?, ?, ?)"#,
+            )
+            .unwrap();
+        for (index, condition) in conditions.iter().enumerate() {
+            stmt.execute((season_schedule_id, session, index + 1, condition))
+                .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+}
+
+pub fn get_race_weather(race_id: &u16, session: &str) -> Vec<String> {
+    let conn = get_connection().unwrap();
+    let mut stmt = conn
+        .prepare(
+            r#"SELECT condition FROM race_weather
+           WHERE fk_season_schedule_id = ? AND session = ?
+           ORDER BY lap_number"#,
+        )
+        .unwrap();
+    let condition_iter = stmt
+        .query_map((race_id, session), |row| row.get(0))
+        .unwrap();
+    condition_iter.filter_map(Result::ok).collect()
+}
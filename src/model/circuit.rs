@@ -1,8 +1,15 @@
+#[derive(Clone)]
 pub struct Circuit {
     pub name: String,
     pub country_id: u8,
     pub city: String,
     pub length_km: f32,
     pub lap_amount: u8,
+    pub track_type: String,
+    pub average_speed_kmh: f32,
+    pub overtaking_difficulty: u8,
+    pub tyre_wear: u8,
+    pub pit_lane_time_loss: f32,
+    pub rain_probability: u8,
     pub image_path: String,
 }
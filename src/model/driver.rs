@@ -1,8 +1,16 @@
+#[derive(Clone)]
 pub struct Driver {
     pub id: u16,
     pub first_name: String,
     pub last_name: String,
-    pub rating: u8,
+    pub pace: u8,
+    pub racecraft: u8,
+    pub consistency: u8,
+    pub tyre_management: u8,
+    pub wet_skill: u8,
+    pub starts: u8,
+    pub experience: u8,
+    pub potential: u8, // Highest overall rating the driver can develop to
     pub country_id: u8,
     pub date_of_birth: String,
     pub racing_number: u8,
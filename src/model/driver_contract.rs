@@ -1,5 +1,8 @@
 pub struct DriverContract {
+    pub team_id: u16,
     pub date_begin: String,
     pub date_end: String,
     pub monthly_wage: f32,
+    pub is_number_one: bool,
+    pub points_bonus: f32, // Paid per championship point scored
 }
@@ -0,0 +1,7 @@
+pub struct DriverRatingChange {
+    pub date: String,
+    pub grand_prix_name: String,
+    pub phase: String, // Mid-season or Season end
+    pub rating_before: u8,
+    pub rating_after: u8,
+}
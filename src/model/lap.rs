@@ -1,4 +1,5 @@
 pub struct Lap {
     pub lap_time_ms: u32,
     pub lap_number: u8,
+    pub position: u8,
 }
@@ -1,20 +1,49 @@
 mod circuit;
 mod driver;
 mod driver_contract;
+mod driver_rating_change;
 mod lap;
+mod news_item;
+mod penalty;
+mod points_system;
+mod power_unit_component;
+mod practice_result;
+mod qualifying_driver_result;
+mod race_decision;
 mod race_driver_result;
+mod race_strategy;
+mod safety_car_period;
 mod season;
 mod season_schedule;
+mod stint;
 mod team;
 
 pub use circuit::Circuit;
 pub use driver::Driver;
 pub use driver_contract::DriverContract;
+pub use driver_rating_change::DriverRatingChange;
 pub use lap::Lap;
+pub use news_item::NewsItem;
+pub use penalty::Penalty;
+pub use points_system::PointsSystem;
+pub use power_unit_component::PowerUnitComponent;
+pub use practice_result::PracticeDriverResult;
+pub use practice_result::PracticePlan;
+pub use practice_result::PracticeResult;
+pub use qualifying_driver_result::QualifyingDriverResult;
+pub use qualifying_driver_result::QualifyingResult;
+pub use race_decision::RaceDecision;
 pub use race_driver_result::RaceDriverResult;
 pub use race_driver_result::RaceResult;
+pub use race_strategy::RaceStrategy;
+pub use race_strategy::StrategyStint;
+pub use safety_car_period::SafetyCarPeriod;
 pub use season::RaceInfo;
+pub use season::Season;
 pub use season::SeasonInfo;
 pub use season_schedule::SeasonSchedule;
+pub use stint::Stint;
+pub use team::CarPerformance;
 pub use team::Team;
 pub use team::TeamBase;
+pub use team::TeamReliability;
@@ -0,0 +1,4 @@
+pub struct NewsItem {
+    pub date: String,
+    pub message: String,
+}
@@ -0,0 +1,7 @@
+pub struct Penalty {
+    pub driver_id: u16,
+    pub kind: String,
+    pub value: u8, // Seconds for a time penalty, places for a grid penalty
+    pub lap: Option<u8>,
+    pub reason: String,
+}
@@ -0,0 +1,6 @@
+#[derive(Clone)]
+pub struct PointsSystem {
+    pub placement_points: Vec<u16>, // Points for 1st place first, placements past the end score none
+    pub fastest_lap_points: u16,
+    pub fastest_lap_max_placement: Option<u8>,
+}
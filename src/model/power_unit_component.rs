@@ -0,0 +1,10 @@
+// One power unit component of a driver's seasonal allocation
+pub struct PowerUnitComponent {
+    pub id: u16,
+    pub driver_id: u16,
+    pub kind: String,
+    pub number: u8,
+    pub is_fitted: bool,
+    pub distance_km: f32, // Covered over all race weekends so far
+    pub failed: bool,
+}
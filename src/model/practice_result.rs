@@ -0,0 +1,27 @@
+pub struct PracticeDriverResult {
+    pub driver_id: u16,
+    pub team_id: u16,
+    pub position: u8,
+    pub best_lap_ms: Option<u32>, // None for a driver who did not run
+    pub laps: u8,
+    pub programme: String,
+    pub qualifying_setup: f32, // Setup knowledge after the session (0-100)
+    pub race_setup: f32,
+}
+
+pub struct PracticeResult {
+    pub position: u8,
+    pub driver_number: u8,
+    pub driver_name: String,
+    pub team: String,
+    pub best_lap_ms: Option<u32>,
+    pub laps: u8,
+    pub programme: String,
+}
+
+// The running the player planned for one of their drivers in a practice session
+pub struct PracticePlan {
+    pub driver_id: u16,
+    pub laps: u8,
+    pub programme: String,
+}
@@ -0,0 +1,18 @@
+pub struct QualifyingDriverResult {
+    pub driver_id: u16,
+    pub team_id: u16,
+    pub grid_position: u8,
+    pub q1_time_ms: u32,
+    pub q2_time_ms: Option<u32>,
+    pub q3_time_ms: Option<u32>,
+}
+
+pub struct QualifyingResult {
+    pub position: u8,
+    pub driver_number: u8,
+    pub driver_name: String,
+    pub team: String,
+    pub q1_time_ms: u32,
+    pub q2_time_ms: Option<u32>,
+    pub q3_time_ms: Option<u32>,
+}
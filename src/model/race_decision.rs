@@ -0,0 +1,7 @@
+// An order the player gave one of their drivers during the race
+pub struct RaceDecision {
+    pub driver_id: u16,
+    pub lap: u8,
+    pub decision: String,
+    pub compound: Option<String>,
+}
@@ -1,16 +1,29 @@
+use crate::model::{Lap, Stint};
+
 pub struct RaceDriverResult {
     pub driver_id: u16,
     pub team_id: u16,
     pub placement: u8,
     pub points: u16,
-    pub status: String,
+    pub status: String, // Finished, Classified, DNF, NC or DSQ
+    pub retired_lap: Option<u8>,
+    pub retirement_reason: Option<String>,
+    pub laps: Vec<Lap>,
+    pub stints: Vec<Stint>,
 }
 
 pub struct RaceResult {
     pub position: u8,
+    pub grid_position: Option<u8>,
     pub driver_number: u8,
     pub driver_name: String,
     pub team: String,
     pub points: u16,
-    pub total_time_ms: u32,
+    pub total_time_ms: u32,   // Including time penalties
+    pub penalty_seconds: u32, // Time penalties added to the race time
+    pub status: String,
+    pub retired_lap: Option<u8>,
+    pub retirement_reason: Option<String>,
+    pub laps: Vec<Lap>,
+    pub stints: Vec<Stint>,
 }
@@ -0,0 +1,14 @@
+// The player's plan for one of their drivers in a race
+#[derive(Clone)]
+pub struct RaceStrategy {
+    pub driver_id: u16,
+    pub fuel_mode: String,
+    pub engine_mode: String,
+    pub stints: Vec<StrategyStint>,
+}
+
+#[derive(Clone)]
+pub struct StrategyStint {
+    pub compound: String,
+    pub end_lap: u8,
+}
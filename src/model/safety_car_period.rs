@@ -0,0 +1,6 @@
+pub struct SafetyCarPeriod {
+    pub kind: String,
+    pub start_lap: u8,
+    pub end_lap: u8,
+    pub reason: String,
+}
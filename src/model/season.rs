@@ -1,3 +1,8 @@
+pub struct Season {
+    pub id: u16,
+    pub year: u16,
+}
+
 pub struct SeasonInfo {
     pub total_points: u16,
     pub overall_position: u8,
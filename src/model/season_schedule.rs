@@ -1,6 +1,10 @@
 pub struct SeasonSchedule {
     pub id: u16,
+    pub season_id: u16,
     pub circuit_id: u16,
     pub date: String,
     pub status: String,
+    pub seed: Option<i64>,
+    pub rain_chance: Option<u8>,
+    pub is_sprint: bool,
 }
@@ -0,0 +1,6 @@
+pub struct Stint {
+    pub stint_number: u8,
+    pub compound: String,
+    pub start_lap: u8,
+    pub end_lap: u8,
+}
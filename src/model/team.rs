@@ -14,3 +14,17 @@ pub struct TeamBase {
     pub city: String,
     pub country_name: String,
 }
+
+#[derive(Clone)]
+pub struct TeamReliability {
+    pub chassis: u8,
+    pub power_unit: u8,
+}
+
+#[derive(Clone)]
+pub struct CarPerformance {
+    pub aero: u8,
+    pub power: u8,
+    pub mechanical_grip: u8,
+    pub reliability: u8,
+}
@@ -1,5 +1,7 @@
 use super::AppState;
-use crate::database::teams::{get_all_teams, save_selected_team};
+use crate::database::teams::{
+    get_all_teams, get_car_performance, get_team_id_by_short_name, save_selected_team,
+};
 use crate::ui::Screen::MainGameScreen;
 use druid::widget::{Button, CrossAxisAlignment, Flex, Label, MainAxisAlignment, Scroll};
 use druid::{Env, Widget, WidgetExt};
@@ -26,6 +28,17 @@ pub fn build_screen() -> impl Widget<AppState> {
             driver_column.add_child(driver_label);
         }
 
+        let team_id = get_team_id_by_short_name(short_name).unwrap();
+        let car = get_car_performance(&team_id).unwrap();
+        driver_column.add_spacer(5.0);
+        driver_column.add_child(
+            Label::new(format!(
+                "Aero: {}  Power: {}  Grip: {}  Reliability: {}",
+                car.aero, car.power, car.mechanical_grip, car.reliability
+            ))
+            .with_text_size(14.0),
+        );
+
         if left_count <= right_count {
             left_column.add_child(driver_column);
             left_column.add_spacer(10.0);
@@ -0,0 +1,50 @@
+use super::super::AppState;
+use druid::kurbo::{BezPath, Circle, Point};
+use druid::widget::{Painter, SizedBox};
+use druid::{Color, RenderContext, Widget};
+
+const CHART_PADDING: f64 = 8.0;
+const POINT_RADIUS: f64 = 3.0;
+const VALUE_MARGIN: f64 = 2.0; // Room above the highest and below the lowest value
+
+// Line chart of the values from left to right, scaled between the lowest and highest value
+pub fn make_line_chart(values: Vec<f64>, width: f64, height: f64) -> impl Widget<AppState> {
+    SizedBox::new(Painter::new(move |ctx, _data: &AppState, _env| {
+        let size = ctx.size();
+        ctx.stroke(size.to_rect(), &Color::grey(0.5), 1.0);
+        if values.is_empty() {
+            return;
+        }
+        let min = values.iter().copied().fold(f64::MAX, f64::min) - VALUE_MARGIN;
+        let max = values.iter().copied().fold(f64::MIN, f64::max) + VALUE_MARGIN;
+        let plot_width = size.width - 2.0 * CHART_PADDING;
+        let plot_height = size.height - 2.0 * CHART_PADDING;
+        let step = if values.len() > 1 {
+            plot_width / (values.len() - 1) as f64
+        } else {
+            0.0
+        };
+        let points: Vec<Point> = values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                Point::new(
+                    CHART_PADDING + index as f64 * step,
+                    CHART_PADDING + plot_height - (value - min) / (max - min) * plot_height,
+                )
+            })
+            .collect();
+
+        let mut path = BezPath::new();
+        path.move_to(points[0]);
+        for point in &points[1..] {
+            path.line_to(*point);
+        }
+        ctx.stroke(path, &Color::rgb8(0x3a, 0x86, 0xff), 2.0);
+        for point in points {
+            ctx.fill(Circle::new(point, POINT_RADIUS), &Color::WHITE);
+        }
+    }))
+    .width(width)
+    .height(height)
+}
@@ -1,11 +1,14 @@
 use super::super::AppState;
 use crate::database::driver::get_driver_id_by_fullname;
-use crate::database::race::get_race_id_by_grandprix_name;
+use crate::database::race::{get_race_id_by_date, get_race_id_by_grandprix_name};
 use crate::database::teams::{get_team_id_by_full_name, get_team_id_by_short_name};
 use crate::ui::Screen::{DriverScreen, RaceScreen, TeamScreen};
 use druid::EventCtx;
 
-pub fn goto_driver() -> Box<dyn Fn(&str) -> Box<dyn Fn(&mut EventCtx, &mut AppState)>> {
+// Makes the click handler for a table cell from its text
+type Goto = Box<dyn Fn(&str) -> Box<dyn Fn(&mut EventCtx, &mut AppState)>>;
+
+pub fn goto_driver() -> Goto {
     Box::new(|driver: &str| {
         let driver = driver.to_string();
         if driver == "TBD" || driver.is_empty() {
@@ -20,7 +23,7 @@ pub fn goto_driver() -> Box<dyn Fn(&str) -> Box<dyn Fn(&mut EventCtx, &mut AppSt
     })
 }
 
-pub fn goto_team() -> Box<dyn Fn(&str) -> Box<dyn Fn(&mut EventCtx, &mut AppState)>> {
+pub fn goto_team() -> Goto {
     Box::new(|team: &str| {
         let team = team.to_string();
         Box::new(move |_ctx: &mut EventCtx, _data: &mut AppState| {
@@ -31,7 +34,7 @@ pub fn goto_team() -> Box<dyn Fn(&str) -> Box<dyn Fn(&mut EventCtx, &mut AppStat
     })
 }
 
-pub fn goto_team_fullname() -> Box<dyn Fn(&str) -> Box<dyn Fn(&mut EventCtx, &mut AppState)>> {
+pub fn goto_team_fullname() -> Goto {
     Box::new(|team: &str| {
         let team = team.to_string();
         Box::new(move |_ctx: &mut EventCtx, _data: &mut AppState| {
@@ -42,11 +45,24 @@ pub fn goto_team_fullname() -> Box<dyn Fn(&str) -> Box<dyn Fn(&mut EventCtx, &mu
     })
 }
 
-pub fn goto_race() -> Box<dyn Fn(&str) -> Box<dyn Fn(&mut EventCtx, &mut AppState)>> {
-    Box::new(|race: &str| {
+// For a table of the races of one season, given by their names
+pub fn goto_race(season_id: u16) -> Goto {
+    Box::new(move |race: &str| {
         let race = race.to_string();
         Box::new(move |_ctx: &mut EventCtx, _data: &mut AppState| {
-            let race_id = get_race_id_by_grandprix_name(&race).unwrap_or(0);
+            let race_id = get_race_id_by_grandprix_name(&race, &season_id).unwrap_or(0);
+            _data.current_screen = RaceScreen { race_id };
+            _ctx.request_update();
+        })
+    })
+}
+
+// For races over several seasons, given by their dates
+pub fn goto_race_on_date() -> Goto {
+    Box::new(|date: &str| {
+        let date = date.to_string();
+        Box::new(move |_ctx: &mut EventCtx, _data: &mut AppState| {
+            let race_id = get_race_id_by_date(&date).unwrap_or(0);
             _data.current_screen = RaceScreen { race_id };
             _ctx.request_update();
         })
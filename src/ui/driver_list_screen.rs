@@ -1,11 +1,23 @@
 use super::AppState;
-use crate::database::driver::get_driver_data;
+use crate::database::driver::{get_all_drivers, get_driver_data};
 use crate::ui::component::goto::{goto_driver, goto_team};
 use crate::ui::component::table::make_table;
 use druid::widget::{Flex, Scroll, SizedBox};
 use druid::Widget;
 
 pub fn build_screen() -> impl Widget<AppState> {
+    let drivers = get_all_drivers();
+    let rows: Vec<Vec<String>> = get_driver_data()
+        .into_iter()
+        .map(|(driver_id, mut row)| {
+            let rating = drivers
+                .iter()
+                .find(|d| d.id == driver_id)
+                .map_or(0, |d| d.rating());
+            row.insert(2, rating.to_string());
+            row
+        })
+        .collect();
     Flex::column().with_spacer(20.0).with_child(
         SizedBox::new(
             Scroll::new(make_table(
@@ -16,7 +28,7 @@ pub fn build_screen() -> impl Widget<AppState> {
                     "Country".to_string(),
                     "Team".to_string(),
                 ],
-                get_driver_data(),
+                rows,
                 vec![(0, goto_driver()), (4, goto_team())],
             ))
             .vertical(),
@@ -1,16 +1,29 @@
-use super::AppState;
+use super::{AppState, ContractNegotiation};
+use crate::backend::contract::{
+    evaluate_offer, opening_offer, sign_contract, ContractOffer, OfferResponse, MAX_CONTRACT_YEARS,
+};
+use crate::database::config::get_current_date;
 use crate::database::country::get_country_image_path;
-use crate::database::driver::{get_driver_by_id, get_driver_contract, get_driver_season_info};
+use crate::database::driver::{
+    get_driver_by_id, get_driver_contracts, get_driver_rating_history, get_driver_season_info,
+};
+use crate::database::season::get_current_season;
+use crate::database::teams::{get_team_id_by_short_name, get_team_info};
 use crate::model::RaceInfo;
-use crate::ui::component::goto::goto_race;
+use crate::ui::component::chart::make_line_chart;
+use crate::ui::component::goto::{goto_race, goto_race_on_date};
 use crate::ui::component::table::make_table;
 use crate::util::image_loader::{get_country, get_driver};
-use druid::widget::{CrossAxisAlignment, Flex, Label, MainAxisAlignment, Scroll, SizedBox};
-use druid::Widget;
+use druid::widget::{
+    Button, CrossAxisAlignment, Flex, Label, MainAxisAlignment, Scroll, SizedBox, ViewSwitcher,
+};
+use druid::{Env, Widget};
+
+const WAGE_STEP: f32 = 10_000.0;
+const POINTS_BONUS_STEP: f32 = 500.0;
 
 pub fn build_screen(driver_id: &u16) -> impl Widget<AppState> {
     let driver = get_driver_by_id(driver_id).unwrap();
-    let driver_contract = get_driver_contract(driver_id).unwrap();
 
     let mut right_column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
     right_column.add_child(get_driver(driver.image_path.as_str()));
@@ -18,7 +31,23 @@ pub fn build_screen(driver_id: &u16) -> impl Widget<AppState> {
         &get_country_image_path(&driver.country_id).unwrap(),
     ));
     right_column.add_spacer(10.0);
-    right_column.add_child(Label::new(format!("Overall Rating:\t{}", driver.rating)));
+    right_column.add_child(Label::new(format!("Overall Rating:\t{}", driver.rating())));
+    right_column.add_spacer(5.0);
+    // the attributes the overall rating is made up of
+    let mut attribute_names = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+    let mut attribute_values = Flex::column().cross_axis_alignment(CrossAxisAlignment::End);
+    for (name, value) in driver.attributes() {
+        attribute_names.add_child(Label::new(format!("{}:", name)));
+        attribute_values.add_child(Label::new(value.to_string()));
+    }
+    right_column.add_child(
+        Flex::row()
+            .with_spacer(10.0)
+            .with_child(attribute_names)
+            .with_spacer(20.0)
+            .with_child(attribute_values),
+    );
+    right_column.add_spacer(10.0);
     right_column.add_child(Label::new(format!(
         "Date of Birth:\t\t{}",
         driver.date_of_birth
@@ -30,20 +59,51 @@ pub fn build_screen(driver_id: &u16) -> impl Widget<AppState> {
     right_column.add_spacer(10.0);
     right_column.add_child(Label::new(format!("Contract Details:")));
     right_column.add_spacer(5.0);
-    right_column.add_child(Label::new(format!(
-        "Start:\t\t\t{}",
-        driver_contract.date_begin
-    )));
-    right_column.add_child(Label::new(format!(
-        "End:\t\t\t{}",
-        driver_contract.date_end
-    )));
-    right_column.add_child(Label::new(format!(
-        "Monthly Wage:\t{}",
-        driver_contract.monthly_wage
-    )));
+    // the current contract and any signed for the future
+    let today = get_current_date().unwrap().to_string();
+    let contracts: Vec<_> = get_driver_contracts(driver_id)
+        .into_iter()
+        .filter(|c| c.date_end >= today)
+        .collect();
+    if contracts.is_empty() {
+        right_column.add_child(Label::new("Free agent"));
+    }
+    for driver_contract in contracts {
+        right_column.add_child(Label::new(format!(
+            "Team:\t\t\t{}",
+            get_team_info(&driver_contract.team_id).map_or(String::new(), |t| t.short_name)
+        )));
+        right_column.add_child(Label::new(format!(
+            "Start:\t\t\t{}",
+            driver_contract.date_begin
+        )));
+        right_column.add_child(Label::new(format!(
+            "End:\t\t\t{}",
+            driver_contract.date_end
+        )));
+        right_column.add_child(Label::new(format!(
+            "Monthly Wage:\t{}",
+            driver_contract.monthly_wage
+        )));
+        right_column.add_child(Label::new(format!(
+            "Number One:\t\t{}",
+            if driver_contract.is_number_one {
+                "Yes"
+            } else {
+                "No"
+            }
+        )));
+        right_column.add_child(Label::new(format!(
+            "Points Bonus:\t{}",
+            driver_contract.points_bonus
+        )));
+        right_column.add_spacer(5.0);
+    }
+    right_column.add_spacer(10.0);
+    right_column.add_child(build_negotiation(*driver_id));
 
-    let season_info = get_driver_season_info(&driver.id, &2025).unwrap();
+    let season = get_current_season().unwrap();
+    let season_info = get_driver_season_info(&driver.id, &season.year).unwrap();
 
     let mut left_column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
     left_column.add_child(Label::new("Season Info:").with_text_size(20.0));
@@ -84,13 +144,56 @@ pub fn build_screen(driver_id: &u16) -> impl Widget<AppState> {
                     "Points".to_string(),
                 ],
                 driver_results_data,
-                vec![(0, goto_race())],
+                vec![(0, goto_race(season.id))],
             ))
             .vertical(),
         )
         .height(400.0),
     );
 
+    // the overall rating before the first development and after each one since
+    let rating_history = get_driver_rating_history(driver_id);
+    let mut ratings = vec![rating_history
+        .first()
+        .map_or(driver.rating(), |change| change.rating_before) as f64];
+    ratings.extend(rating_history.iter().map(|c| c.rating_after as f64));
+    left_column.add_spacer(10.0);
+    left_column.add_child(Label::new("Rating Progression:").with_text_size(20.0));
+    left_column.add_spacer(7.5);
+    left_column.add_child(make_line_chart(ratings, 400.0, 120.0));
+    left_column.add_spacer(5.0);
+    left_column.add_child(
+        SizedBox::new(
+            Scroll::new(make_table(
+                vec![
+                    "Race".to_string(),
+                    "Date".to_string(),
+                    "Development".to_string(),
+                    "Rating".to_string(),
+                ],
+                rating_history
+                    .iter()
+                    .map(|change| {
+                        vec![
+                            change.grand_prix_name.clone(),
+                            change.date.clone(),
+                            change.phase.clone(),
+                            format!(
+                                "{} -> {} ({:+})",
+                                change.rating_before,
+                                change.rating_after,
+                                change.rating_after as i16 - change.rating_before as i16
+                            ),
+                        ]
+                    })
+                    .collect(),
+                vec![(1, goto_race_on_date())],
+            ))
+            .vertical(),
+        )
+        .height(150.0),
+    );
+
     let layout = Flex::row()
         .main_axis_alignment(MainAxisAlignment::Center)
         .cross_axis_alignment(CrossAxisAlignment::Start)
@@ -107,3 +210,150 @@ pub fn build_screen(driver_id: &u16) -> impl Widget<AppState> {
         .with_spacer(20.0)
         .with_child(layout)
 }
+
+fn offer_from(negotiation: &ContractNegotiation) -> ContractOffer {
+    ContractOffer {
+        monthly_wage: negotiation.monthly_wage,
+        years: negotiation.years,
+        is_number_one: negotiation.is_number_one,
+        points_bonus: negotiation.points_bonus,
+    }
+}
+
+// Contract talks with the driver for the player's team, each offer gets an answer straight away
+fn build_negotiation(driver_id: u16) -> impl Widget<AppState> {
+    ViewSwitcher::new(
+        |data: &AppState, _env: &Env| (data.negotiation.clone(), data.selected_team.clone()),
+        move |(negotiation, selected_team), _data: &AppState, _env| {
+            let Some(team_id) = selected_team.as_deref().and_then(get_team_id_by_short_name) else {
+                return Box::new(Flex::column());
+            };
+            let Some(negotiation) = negotiation.clone().filter(|n| n.driver_id == driver_id) else {
+                return Box::new(Button::new("Negotiate Contract").on_click(
+                    move |_ctx, data: &mut AppState, _env| {
+                        let offer = opening_offer(driver_id, team_id);
+                        data.negotiation = Some(ContractNegotiation {
+                            driver_id,
+                            monthly_wage: offer.monthly_wage,
+                            years: offer.years,
+                            is_number_one: offer.is_number_one,
+                            points_bonus: offer.points_bonus,
+                            round: 0,
+                            message: String::new(),
+                            finished: false,
+                        });
+                    },
+                ));
+            };
+
+            let mut column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+            column.add_child(Label::new("Contract Negotiation:").with_text_size(20.0));
+            column.add_spacer(5.0);
+            if !negotiation.message.is_empty() {
+                column.add_child(Label::new(negotiation.message.clone()));
+                column.add_spacer(5.0);
+            }
+            if negotiation.finished {
+                column.add_child(Button::new("Close").on_click(
+                    |_ctx, data: &mut AppState, _env| {
+                        data.negotiation = None;
+                    },
+                ));
+                return Box::new(column);
+            }
+
+            // changes the offer on the table
+            let adjust = |label: String, change: fn(&mut ContractNegotiation, f32)| {
+                Flex::row()
+                    .with_child(Button::new("-").on_click(
+                        move |_ctx, data: &mut AppState, _env| {
+                            if let Some(negotiation) = data.negotiation.as_mut() {
+                                change(negotiation, -1.0);
+                            }
+                        },
+                    ))
+                    .with_spacer(5.0)
+                    .with_child(Button::new("+").on_click(
+                        move |_ctx, data: &mut AppState, _env| {
+                            if let Some(negotiation) = data.negotiation.as_mut() {
+                                change(negotiation, 1.0);
+                            }
+                        },
+                    ))
+                    .with_spacer(10.0)
+                    .with_child(Label::new(label))
+            };
+            column.add_child(adjust(
+                format!("Monthly Wage: {:.0}", negotiation.monthly_wage),
+                |n, sign| n.monthly_wage = (n.monthly_wage + sign * WAGE_STEP).max(0.0),
+            ));
+            column.add_child(adjust(
+                format!("Length: {} years", negotiation.years),
+                |n, sign| {
+                    n.years = (n.years as f32 + sign).clamp(1.0, MAX_CONTRACT_YEARS as f32) as u8
+                },
+            ));
+            column.add_child(adjust(
+                format!("Points Bonus: {:.0} per point", negotiation.points_bonus),
+                |n, sign| n.points_bonus = (n.points_bonus + sign * POINTS_BONUS_STEP).max(0.0),
+            ));
+            column.add_child(
+                Button::new(if negotiation.is_number_one {
+                    "Number One: Yes"
+                } else {
+                    "Number One: No"
+                })
+                .on_click(|_ctx, data: &mut AppState, _env| {
+                    if let Some(negotiation) = data.negotiation.as_mut() {
+                        negotiation.is_number_one = !negotiation.is_number_one;
+                    }
+                }),
+            );
+            column.add_spacer(5.0);
+            column.add_child(
+                Flex::row()
+                    .with_child(Button::new("Make Offer").on_click(
+                        move |_ctx, data: &mut AppState, _env| {
+                            let Some(negotiation) = data.negotiation.as_mut() else {
+                                return;
+                            };
+                            negotiation.round += 1;
+                            let offer = offer_from(negotiation);
+                            match evaluate_offer(driver_id, team_id, &offer, negotiation.round) {
+                                OfferResponse::Accepted => {
+                                    sign_contract(
+                                        driver_id,
+                                        team_id,
+                                        &offer,
+                                        get_current_date().unwrap(),
+                                    );
+                                    negotiation.message =
+                                        "The driver signed the contract".to_string();
+                                    negotiation.finished = true;
+                                }
+                                OfferResponse::CounterOffer(counter) => {
+                                    negotiation.message = format!(
+                                        "The driver asks for {:.0} a month over {} years",
+                                        counter.monthly_wage, counter.years
+                                    );
+                                    negotiation.monthly_wage = counter.monthly_wage;
+                                    negotiation.years = counter.years;
+                                }
+                                OfferResponse::Rejected(reason) => {
+                                    negotiation.message = reason;
+                                    negotiation.finished = true;
+                                }
+                            }
+                        },
+                    ))
+                    .with_spacer(5.0)
+                    .with_child(Button::new("End Talks").on_click(
+                        |_ctx, data: &mut AppState, _env| {
+                            data.negotiation = None;
+                        },
+                    )),
+            );
+            Box::new(column)
+        },
+    )
+}
@@ -1,5 +1,6 @@
 use super::AppState;
 use crate::database::driver::get_top_driver_standings;
+use crate::database::season::{get_champions, get_current_season};
 use crate::database::teams::get_top_teams_standings;
 use crate::ui::component::goto::{goto_driver, goto_team};
 use crate::ui::component::table::make_table;
@@ -7,6 +8,7 @@ use druid::widget::{CrossAxisAlignment, Flex, Label, MainAxisAlignment, Scroll,
 use druid::Widget;
 
 pub fn build_screen() -> impl Widget<AppState> {
+    let season = get_current_season().unwrap();
     let mut left_column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
     left_column.add_child(Label::new("Top 3 drivers standings").with_text_size(20.0));
     left_column.add_spacer(5.0);
@@ -18,7 +20,7 @@ pub fn build_screen() -> impl Widget<AppState> {
                     "Driver Name".to_string(),
                     "Points".to_string(),
                 ],
-                get_top_driver_standings(None),
+                get_top_driver_standings(&season.id, None),
                 vec![(1, goto_driver())],
             ))
             .vertical(),
@@ -38,7 +40,7 @@ pub fn build_screen() -> impl Widget<AppState> {
                     "Team Name".to_string(),
                     "Points".to_string(),
                 ],
-                get_top_teams_standings(None),
+                get_top_teams_standings(&season.id, None),
                 vec![(1, goto_team())],
             ))
             .vertical(),
@@ -55,5 +57,30 @@ pub fn build_screen() -> impl Widget<AppState> {
         .with_spacer(40.0)
         .with_flex_child(right_column, 1.0);
 
-    Flex::column().with_spacer(20.0).with_child(layout)
+    let mut champions_column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+    champions_column.add_child(Label::new("Past champions").with_text_size(20.0));
+    champions_column.add_spacer(5.0);
+    champions_column.add_child(
+        SizedBox::new(
+            Scroll::new(make_table(
+                vec![
+                    "Season".to_string(),
+                    "Drivers' Champion".to_string(),
+                    "Points".to_string(),
+                    "Teams' Champion".to_string(),
+                    "Points".to_string(),
+                ],
+                get_champions(),
+                vec![(1, goto_driver()), (3, goto_team())],
+            ))
+            .vertical(),
+        )
+        .height(200.0),
+    );
+
+    Flex::column()
+        .with_spacer(20.0)
+        .with_child(layout)
+        .with_spacer(20.0)
+        .with_child(champions_column)
 }
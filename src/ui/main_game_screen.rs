@@ -1,11 +1,15 @@
 use super::component::goto::goto_race;
+use crate::backend::race::finalise_races;
+use crate::backend::season::start_next_season;
 use crate::database::config::{get_current_date, update_current_date};
 use crate::database::driver::get_top_driver_standings;
+use crate::database::news::get_latest_news;
 use crate::database::race::{get_next_race, get_race_list};
+use crate::database::season::get_current_season;
 use crate::database::teams::{get_own_team_standing, get_top_teams_standings};
 use crate::ui::component::goto::{goto_driver, goto_team};
 use crate::ui::component::table::make_table;
-use crate::ui::Screen::RaceScreen;
+use crate::ui::Screen::{RaceScheduleScreen, RaceScreen};
 use crate::ui::{AppState, SET_CURRENT_DATE};
 use chrono::NaiveDate;
 use druid::widget::{
@@ -33,6 +37,7 @@ impl<W: Widget<AppState>> Controller<AppState, W> for InitDateController {
 }
 
 pub fn build_screen() -> impl Widget<AppState> {
+    let season = get_current_season().unwrap();
     let no_races_left_string = "Next Year".to_string();
     let next_race_day: String = match get_next_race() {
         Some(race) => NaiveDate::parse_from_str(&race.date, "%Y-%m-%d")
@@ -43,6 +48,9 @@ pub fn build_screen() -> impl Widget<AppState> {
 
     let new_action_button =
         Button::new("Next Action").on_click(move |_ctx, _data: &mut AppState, _env| {
+            // moving on ends the playback of the last race and its result is final
+            _data.playback = None;
+            finalise_races();
             let next_race = get_next_race().unwrap();
             let next_race_day = NaiveDate::parse_from_str(&next_race.date, "%Y-%m-%d").unwrap();
 
@@ -58,6 +66,17 @@ pub fn build_screen() -> impl Widget<AppState> {
             }
         });
 
+    // the season is over, it is closed and the new calendar is shown
+    let next_season_button =
+        Button::new("Start Next Season").on_click(|_ctx, _data: &mut AppState, _env| {
+            _data.playback = None;
+            if let Some(next_season_start) = start_next_season() {
+                _data.current_date = next_season_start.to_string();
+                _data.current_screen = RaceScheduleScreen;
+                _ctx.request_update();
+            }
+        });
+
     let mut column1 = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
     column1.add_child(Label::new("Race List").with_text_size(20.0));
     column1.add_spacer(5.0);
@@ -70,8 +89,8 @@ pub fn build_screen() -> impl Widget<AppState> {
                     "Winner".to_string(),
                     "MyTeam Positions".to_string(),
                 ],
-                get_race_list(),
-                vec![(1, goto_race()), (2, goto_driver())],
+                get_race_list(&season.id),
+                vec![(1, goto_race(season.id)), (2, goto_driver())],
             ))
             .vertical(),
         )
@@ -79,8 +98,27 @@ pub fn build_screen() -> impl Widget<AppState> {
     );
     column1.add_spacer(10.0);
 
+    column1.add_child(Label::new("News").with_text_size(20.0));
+    column1.add_spacer(5.0);
+    let news_rows: Vec<Vec<String>> = get_latest_news(10)
+        .into_iter()
+        .map(|news| vec![news.date, news.message])
+        .collect();
+    column1.add_child(
+        SizedBox::new(
+            Scroll::new(make_table(
+                vec!["Date".to_string(), "Message".to_string()],
+                news_rows,
+                vec![],
+            ))
+            .vertical(),
+        )
+        .height(200.0),
+    );
+    column1.add_spacer(10.0);
+
     let (team_name, drivers, total_points) =
-        get_own_team_standing().unwrap_or(("".to_string(), vec![], 0));
+        get_own_team_standing(&season.id).unwrap_or(("".to_string(), vec![], 0));
 
     let mut column2 = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
 
@@ -92,6 +130,8 @@ pub fn build_screen() -> impl Widget<AppState> {
     ));
     if next_race_day != no_races_left_string {
         column2.add_child(new_action_button);
+    } else {
+        column2.add_child(next_season_button);
     }
 
     column2.add_spacer(10.0);
@@ -118,7 +158,7 @@ pub fn build_screen() -> impl Widget<AppState> {
             "Driver Name".to_string(),
             "Points".to_string(),
         ],
-        get_top_driver_standings(Some(3)),
+        get_top_driver_standings(&season.id, Some(3)),
         vec![(1, goto_driver())],
     ));
     column2.add_spacer(10.0);
@@ -131,7 +171,7 @@ pub fn build_screen() -> impl Widget<AppState> {
             "Team Name".to_string(),
             "Points".to_string(),
         ],
-        get_top_teams_standings(Some(3)),
+        get_top_teams_standings(&season.id, Some(3)),
         vec![(1, goto_team())],
     ));
     //////////////////////////////
@@ -1,3 +1,4 @@
+use crate::backend::predictor::RacePrediction;
 use crate::ui::Screen::{
     DriverListScreen, Leaderboard, MainGameScreen, RaceScheduleScreen, TeamListScreen,
 };
@@ -6,11 +7,16 @@ use druid::widget::{Button, Flex, ViewSwitcher};
 use druid::{
     Color, Command, Data, DelegateCtx, Env, Handled, Lens, Selector, Target, Widget, WidgetExt,
 };
+use std::sync::Arc;
 
 pub const SET_CURRENT_DATE: Selector<String> = Selector::new("app.set-current-date");
 pub const RESET_GAME_STATE: Selector = Selector::new("app.reset-game-state");
 pub const SET_SCREEN: Selector<Screen> = Selector::new("app.set-screen");
 pub const SHOW_ERROR: Selector<String> = Selector::new("app.show-error");
+pub const PREDICTION_READY: Selector<(u16, Arc<RacePrediction>)> =
+    Selector::new("app.prediction-ready");
+pub const STRATEGY_ESTIMATE_READY: Selector<StrategyEstimate> =
+    Selector::new("app.strategy-estimate-ready");
 
 mod choose_team_screen;
 mod driver_list_screen;
@@ -19,8 +25,10 @@ mod leaderboard_screen;
 mod loading_screen;
 mod main_game_screen;
 mod main_screen;
+mod practice_screen;
 mod race_schedule_screen;
 mod race_screen;
+mod strategy_screen;
 mod team_list_screen;
 mod team_screen;
 
@@ -34,8 +42,48 @@ pub struct AppState {
     pub current_date: String,
     pub last_race_update_time: String,
     pub show_modal: bool,
+    pub prediction_race_id: Option<u16>,
+    pub prediction: Option<(u16, Arc<RacePrediction>)>, // For the race it was simulated for
+    pub playback: Option<RacePlayback>,
+    pub negotiation: Option<ContractNegotiation>,
+    pub strategy_estimates: Arc<Vec<StrategyEstimate>>,
 }
 
+// Lap by lap replay of a race that has just been simulated
+#[derive(Clone, PartialEq, Data)]
+pub struct RacePlayback {
+    pub race_id: u16,
+    pub lap: u8,
+    pub lap_amount: u8,
+    pub playing: bool,
+    pub speed: u8,
+}
+
+// Contract talks between the player's team and a driver, holding the offer on the table
+#[derive(Clone, PartialEq, Data)]
+pub struct ContractNegotiation {
+    pub driver_id: u16,
+    pub monthly_wage: f32,
+    pub years: u8,
+    pub is_number_one: bool,
+    pub points_bonus: f32,
+    pub round: u8,
+    pub message: String,
+    pub finished: bool, // The driver signed or walked away
+}
+
+// Race time a plan gains or loses against the team's strategy, simulated in the background
+#[derive(Clone, PartialEq, Data)]
+pub struct StrategyEstimate {
+    pub race_id: u16,
+    pub driver_id: u16,
+    pub plan: String,       // The plan it is estimated for
+    pub delta: Option<f32>, // None if the driver never finished in the simulations
+    pub ready: bool,
+}
+
+// the screens are named after their modules, so most of them end in Screen
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, PartialEq, Eq, Data)]
 pub enum Screen {
     Loading,
@@ -49,6 +97,8 @@ pub enum Screen {
     DriverScreen { driver_id: u16 },
     DriverListScreen,
     RaceScheduleScreen,
+    StrategyScreen { race_id: u16 },
+    PracticeScreen { race_id: u16 },
 }
 
 impl Default for AppState {
@@ -60,6 +110,11 @@ impl Default for AppState {
             show_modal: false,
             current_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().to_string(),
             last_race_update_time: Utc::now().to_string(),
+            prediction_race_id: None,
+            prediction: None,
+            playback: None,
+            negotiation: None,
+            strategy_estimates: Arc::new(Vec::new()),
         }
     }
 }
@@ -97,6 +152,12 @@ pub fn build_ui() -> impl druid::Widget<AppState> {
                 Screen::RaceScheduleScreen => {
                     Box::new(with_navbar(race_schedule_screen::build_screen()))
                 }
+                Screen::StrategyScreen { race_id } => {
+                    Box::new(with_navbar(strategy_screen::build_screen(*race_id)))
+                }
+                Screen::PracticeScreen { race_id } => {
+                    Box::new(with_navbar(practice_screen::build_screen(*race_id)))
+                }
             }
         },
     )
@@ -179,6 +240,24 @@ impl druid::AppDelegate<AppState> for MyAppDelegate {
             data.current_screen = Screen::Main;
             data.game_number.clear();
             Handled::Yes
+        } else if let Some((race_id, prediction)) = cmd.get(PREDICTION_READY) {
+            if data.prediction_race_id == Some(*race_id) {
+                data.prediction = Some((*race_id, prediction.clone()));
+            }
+            Handled::Yes
+        } else if let Some(estimate) = cmd.get(STRATEGY_ESTIMATE_READY) {
+            let mut estimates: Vec<StrategyEstimate> = data
+                .strategy_estimates
+                .iter()
+                .filter(|e| {
+                    (e.race_id, e.driver_id, &e.plan)
+                        != (estimate.race_id, estimate.driver_id, &estimate.plan)
+                })
+                .cloned()
+                .collect();
+            estimates.push(estimate.clone());
+            data.strategy_estimates = Arc::new(estimates);
+            Handled::Yes
         } else if let Some(new_screen) = cmd.get(SET_SCREEN) {
             data.current_screen = new_screen.clone();
             Handled::Yes
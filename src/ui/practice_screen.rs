@@ -0,0 +1,175 @@
+use super::AppState;
+use crate::backend::practice::{
+    change_practice_laps, change_practice_programme, next_practice_session, practice_plan,
+    practice_sessions, run_practice_session, PRACTICE_LAP_STEP,
+};
+use crate::database::circuit::get_circuit_by_id;
+use crate::database::driver::{get_all_drivers, get_team_id_by_driver_id};
+use crate::database::practice::{get_practice_results, get_setup_knowledge};
+use crate::database::race::{get_season_schedule_by_id, is_next_race};
+use crate::database::teams::get_team_id_by_short_name;
+use crate::ui::component::goto::{goto_driver, goto_team};
+use crate::ui::component::table::make_table;
+use crate::ui::Screen::RaceScreen;
+use crate::ui::ViewSwitcher;
+use crate::util::time::format_lap_time;
+use chrono::Utc;
+use druid::widget::{Button, CrossAxisAlignment, Flex, Label, Scroll, SizedBox};
+use druid::{Env, Widget, WidgetExt};
+
+pub fn build_screen(race_id: u16) -> impl Widget<AppState> {
+    let race = get_season_schedule_by_id(&race_id).unwrap();
+    let circuit = get_circuit_by_id(&race.circuit_id).unwrap();
+    let back_btn = Button::new("Back to Race").on_click(move |ctx, data: &mut AppState, _env| {
+        data.current_screen = RaceScreen { race_id };
+        ctx.request_update();
+    });
+
+    let practice_switcher = ViewSwitcher::new(
+        |data: &AppState, _env: &Env| {
+            (
+                data.last_race_update_time.clone(),
+                data.selected_team.clone(),
+            )
+        },
+        move |(_, selected_team), _data: &AppState, _env| {
+            let mut column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+            let next_session = next_practice_session(race_id).filter(|_| is_next_race(&race_id));
+            if let Some(session) = next_session {
+                let team_id = selected_team.as_deref().and_then(get_team_id_by_short_name);
+                column.add_child(build_plans(race_id, session, team_id));
+                column.add_spacer(20.0);
+            }
+            for session in practice_sessions(race.is_sprint) {
+                let results = get_practice_results(&race_id, session);
+                if results.is_empty() {
+                    continue;
+                }
+                column.add_child(build_session_results(race_id, session));
+                column.add_spacer(20.0);
+            }
+            Box::new(Scroll::new(column).vertical())
+        },
+    );
+
+    Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(Label::new(format!("Practice: {}", circuit.name)).with_text_size(20.0))
+        .with_spacer(10.0)
+        .with_child(back_btn)
+        .with_spacer(20.0)
+        .with_flex_child(practice_switcher, 1.0)
+        .padding(10.0)
+}
+
+// The running plan of the player's drivers for the next session, and the button to run it
+fn build_plans(race_id: u16, session: &'static str, team_id: Option<u16>) -> impl Widget<AppState> {
+    let driver_ids: Vec<u16> = get_all_drivers()
+        .into_iter()
+        .map(|d| d.id)
+        .filter(|id| team_id.is_some() && get_team_id_by_driver_id(id) == team_id)
+        .collect();
+    let setup_knowledge = get_setup_knowledge(&race_id);
+
+    let mut column = Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(Label::new(format!("{} Plan", session)).with_text_size(16.0));
+    for driver in get_all_drivers()
+        .into_iter()
+        .filter(|d| driver_ids.contains(&d.id))
+    {
+        let driver_id = driver.id;
+        let teammate_id = driver_ids.iter().copied().find(|&id| id != driver_id);
+        let plan = practice_plan(race_id, session, driver_id);
+        let (qualifying, race) = setup_knowledge
+            .iter()
+            .find(|(id, _, _)| *id == driver_id)
+            .map_or((0.0, 0.0), |(_, qualifying, race)| (*qualifying, *race));
+
+        let mut row = Flex::row()
+            .with_child(Label::new(format!(
+                "{} {}: {} laps",
+                driver.first_name, driver.last_name, plan.laps
+            )))
+            .with_spacer(5.0);
+        for laps in [-(PRACTICE_LAP_STEP as i16), PRACTICE_LAP_STEP as i16] {
+            row.add_child(Button::new(format!("{:+}", laps)).on_click(
+                move |_ctx, data: &mut AppState, _env| {
+                    change_practice_laps(race_id, session, driver_id, teammate_id, laps);
+                    data.last_race_update_time = Utc::now().to_string();
+                },
+            ));
+            row.add_spacer(5.0);
+        }
+        row.add_child(Button::new(plan.programme).on_click(
+            move |_ctx, data: &mut AppState, _env| {
+                change_practice_programme(race_id, session, driver_id);
+                data.last_race_update_time = Utc::now().to_string();
+            },
+        ));
+        row.add_spacer(10.0);
+        row.add_child(Label::new(format!(
+            "Setup: qualifying {:.0}%, race {:.0}%",
+            qualifying, race
+        )));
+        column.add_child(row);
+    }
+    column.add_spacer(10.0);
+    column.add_child(Button::new(format!("Run {}", session)).on_click(
+        move |_ctx, data: &mut AppState, _env| {
+            run_practice_session(race_id, session);
+            data.last_race_update_time = Utc::now().to_string();
+        },
+    ));
+    column
+}
+
+fn build_session_results(race_id: u16, session: &str) -> impl Widget<AppState> {
+    let results = get_practice_results(&race_id, session);
+    let fastest_ms = results.first().and_then(|r| r.best_lap_ms);
+    let rows: Vec<Vec<String>> = results
+        .into_iter()
+        .map(|r| {
+            vec![
+                r.position.to_string(),
+                r.driver_number.to_string(),
+                r.driver_name,
+                r.team,
+                r.best_lap_ms.map_or("-".to_string(), format_lap_time),
+                match (r.best_lap_ms, fastest_ms) {
+                    (Some(best), Some(fastest)) if best > fastest => {
+                        format!("+{:.3}", (best - fastest) as f32 / 1000.0)
+                    }
+                    _ => "-".to_string(),
+                },
+                r.laps.to_string(),
+                r.programme,
+            ]
+        })
+        .collect();
+
+    Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(Label::new(format!("{} Results", session)))
+        .with_spacer(10.0)
+        .with_child(
+            SizedBox::new(
+                Scroll::new(make_table(
+                    vec![
+                        "Position".into(),
+                        "DriverNumber".into(),
+                        "DriverName".into(),
+                        "Team".into(),
+                        "Best Lap".into(),
+                        "Gap".into(),
+                        "Laps".into(),
+                        "Programme".into(),
+                    ],
+                    rows,
+                    vec![(2, goto_driver()), (3, goto_team())],
+                ))
+                .vertical(),
+            )
+            .height(300.0),
+        )
+}
@@ -1,11 +1,13 @@
 use super::AppState;
 use crate::database::race::get_race_schedule_info;
+use crate::database::season::get_current_season;
 use crate::ui::component::goto::{goto_driver, goto_race};
 use crate::ui::component::table::make_table;
 use druid::widget::{Flex, Scroll, SizedBox};
 use druid::Widget;
 
 pub fn build_screen() -> impl Widget<AppState> {
+    let season = get_current_season().unwrap();
     Flex::column().with_spacer(20.0).with_child(
         SizedBox::new(
             Scroll::new(make_table(
@@ -18,9 +20,9 @@ pub fn build_screen() -> impl Widget<AppState> {
                     "2nd".to_string(),
                     "3rd".to_string(),
                 ],
-                get_race_schedule_info(),
+                get_race_schedule_info(&season.id),
                 vec![
-                    (2, goto_race()),
+                    (2, goto_race(season.id)),
                     (4, goto_driver()),
                     (5, goto_driver()),
                     (6, goto_driver()),
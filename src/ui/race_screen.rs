@@ -1,22 +1,128 @@
-use super::AppState;
-use crate::backend::race::start_race;
+use super::{AppState, RacePlayback, PREDICTION_READY};
+use crate::backend::decision::{is_decision_point, DriverOrder, RaceOrder};
+use crate::backend::playback::{events_up_to_lap, race_distance, standings_after_lap};
+use crate::backend::predictor::predict_race;
+use crate::backend::race::{finalise_races, give_race_order, grid_penalties, start_race};
+use crate::backend::tyre::{Compound, TyreMode};
+use crate::backend::weather::race_forecast;
 use crate::database::circuit::get_circuit_by_id;
 use crate::database::country::get_country_image_path;
+use crate::database::decision::get_race_decisions;
+use crate::database::driver::{get_driver_by_id, get_driver_id_by_fullname};
+use crate::database::penalty::get_penalties;
+use crate::database::qualifying::get_qualifying_results;
 use crate::database::race::{get_race_results, get_season_schedule_by_id, is_next_race};
+use crate::database::safety_car::get_safety_car_periods;
+use crate::database::teams::get_team_info;
+use crate::database::weather::get_race_weather;
 use crate::ui::component::goto::{goto_driver, goto_team};
 use crate::ui::component::table::make_table;
-use crate::ui::Screen::RaceScreen;
+use crate::ui::Screen::{PracticeScreen, RaceScreen, StrategyScreen};
 use crate::ui::ViewSwitcher;
 use crate::util::image_loader::{get_circuit, get_country};
-use crate::util::time::format_time;
+use crate::util::time::{format_gap, format_lap_time, format_laps_down, format_time};
 use chrono::Utc;
 use druid::widget::{
-    Button, Container, CrossAxisAlignment, Flex, Label, MainAxisAlignment, Scroll, SizedBox,
+    Button, Container, Controller, CrossAxisAlignment, Flex, Label, MainAxisAlignment, Scroll,
+    SizedBox,
 };
-use druid::{Color, Env, Widget, WidgetExt};
+use druid::{
+    Color, Env, Event, EventCtx, LifeCycle, LifeCycleCtx, Target, TimerToken, UpdateCtx, Widget,
+    WidgetExt,
+};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const PLAYBACK_LAP_INTERVAL_MS: u64 = 1000; // At 1x speed
+const MAX_PLAYBACK_SPEED: u8 = 8;
+const PLAYBACK_EVENT_COUNT: usize = 10;
+
+// Advances the race playback one lap every tick while it is playing, and pauses it where the
+// player gets to give orders
+struct PlaybackController {
+    race_id: u16,
+    timer: TimerToken,
+}
+
+impl PlaybackController {
+    fn tick_interval(&self, data: &AppState) -> Option<Duration> {
+        if self.timer != TimerToken::INVALID {
+            return None;
+        }
+        data.playback
+            .as_ref()
+            .filter(|p| p.race_id == self.race_id && p.playing)
+            .map(|p| Duration::from_millis(PLAYBACK_LAP_INTERVAL_MS / p.speed as u64))
+    }
+}
+
+impl<W: Widget<AppState>> Controller<AppState, W> for PlaybackController {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut AppState,
+        env: &Env,
+    ) {
+        if let Event::Timer(token) = event {
+            if *token == self.timer {
+                self.timer = TimerToken::INVALID;
+                if let Some(playback) = data
+                    .playback
+                    .as_mut()
+                    .filter(|p| p.race_id == self.race_id && p.playing)
+                {
+                    playback.lap += 1;
+                    playback.playing = playback.lap < playback.lap_amount
+                        && !is_decision_point(
+                            playback.lap,
+                            playback.lap_amount,
+                            &get_race_weather(&self.race_id, "Race"),
+                            &get_safety_car_periods(&self.race_id, "Race"),
+                        );
+                }
+                return;
+            }
+        }
+        child.event(ctx, event, data, env);
+    }
+
+    fn lifecycle(
+        &mut self,
+        child: &mut W,
+        ctx: &mut LifeCycleCtx,
+        event: &LifeCycle,
+        data: &AppState,
+        env: &Env,
+    ) {
+        if let LifeCycle::WidgetAdded = event {
+            if let Some(interval) = self.tick_interval(data) {
+                self.timer = ctx.request_timer(interval);
+            }
+        }
+        child.lifecycle(ctx, event, data, env);
+    }
+
+    fn update(
+        &mut self,
+        child: &mut W,
+        ctx: &mut UpdateCtx,
+        old_data: &AppState,
+        data: &AppState,
+        env: &Env,
+    ) {
+        if let Some(interval) = self.tick_interval(data) {
+            self.timer = ctx.request_timer(interval);
+        }
+        child.update(ctx, old_data, data, env);
+    }
+}
 
 pub fn build_screen(race_id: u16) -> impl Widget<AppState> {
-    let circuit_data = get_circuit_by_id(&race_id).unwrap();
+    let race = get_season_schedule_by_id(&race_id).unwrap();
+    let circuit_data = get_circuit_by_id(&race.circuit_id).unwrap();
     let circuit_image = get_circuit(&circuit_data.image_path)
         .fix_width(400.0)
         .fix_height(300.0);
@@ -28,61 +134,101 @@ pub fn build_screen(race_id: u16) -> impl Widget<AppState> {
             .with_child(Label::new(format!("Circuit: {}", circuit_data.name)))
             .with_child(Label::new(format!("Location: {}", circuit_data.city)))
             .with_child(Label::new(format!("Length: {} km", circuit_data.length_km)))
-            .with_child(Label::new(format!("Laps: {}", circuit_data.lap_amount))),
+            .with_child(Label::new(format!("Laps: {}", circuit_data.lap_amount)))
+            .with_child(Label::new(format!(
+                "Track Type: {}",
+                circuit_data.track_type
+            )))
+            .with_child(Label::new(format!(
+                "Average Speed: {} km/h",
+                circuit_data.average_speed_kmh
+            )))
+            .with_child(Label::new(format!(
+                "Overtaking Difficulty: {}/10",
+                circuit_data.overtaking_difficulty
+            )))
+            .with_child(Label::new(format!(
+                "Tyre Wear: {}/10",
+                circuit_data.tyre_wear
+            )))
+            .with_child(Label::new(format!(
+                "Pit Lane Time Loss: {} s",
+                circuit_data.pit_lane_time_loss
+            ))),
     )
     .padding(10.0)
     .border(Color::grey(0.5), 1.0);
 
     let results_switcher = ViewSwitcher::new(
-        |data: &AppState, _env: &Env| data.last_race_update_time.clone(),
-        move |_key, _data: &AppState, _env| {
-            if get_season_schedule_by_id(&race_id).unwrap().status == "Finished" {
-                let results = get_race_results(&race_id);
-                let rows: Vec<Vec<String>> = results
-                    .into_iter()
-                    .map(|r| {
-                        vec![
-                            r.position.to_string(),
-                            r.driver_number.to_string(),
-                            r.driver_name,
-                            r.team,
-                            r.points.to_string(),
-                            format_time(r.total_time_ms),
-                        ]
-                    })
-                    .collect();
-                Box::new(
-                    Flex::column()
-                        .with_child(Label::new("Race Results"))
-                        .with_spacer(10.0)
-                        .with_child(
-                            SizedBox::new(
-                                Scroll::new(make_table(
-                                    vec![
-                                        "Position".into(),
-                                        "DriverNumber".into(),
-                                        "DriverName".into(),
-                                        "Team".into(),
-                                        "Points".into(),
-                                        "Total Time".into(),
-                                    ],
-                                    rows,
-                                    vec![(2, goto_driver()), (3, goto_team())],
-                                ))
-                                .vertical(),
-                            )
-                            .height(500.0),
-                        ),
-                )
+        |data: &AppState, _env: &Env| (data.last_race_update_time.clone(), data.playback.clone()),
+        move |_key, data: &AppState, _env| {
+            let race = get_season_schedule_by_id(&race_id).unwrap();
+            if let Some(playback) = data.playback.as_ref().filter(|p| p.race_id == race_id) {
+                Box::new(build_playback(playback, data.selected_team.as_deref()))
+            } else if race.status == "Finished" {
+                // a sprint weekend has four sessions to show, so the tables get less room each
+                let table_height = if race.is_sprint { 150.0 } else { 300.0 };
+                let mut weekend_results = Flex::column()
+                    .with_child(Label::new(format!(
+                        "Seed: {}",
+                        race.seed.map_or("-".to_string(), |seed| seed.to_string())
+                    )))
+                    .with_child(practice_button(race_id));
+                weekend_results.add_spacer(10.0);
+                weekend_results.add_child(build_session_results(race_id, "Race", table_height));
+                if race.is_sprint {
+                    weekend_results.add_spacer(20.0);
+                    weekend_results.add_child(build_session_results(
+                        race_id,
+                        "Sprint",
+                        table_height,
+                    ));
+                }
+                Box::new(weekend_results)
             } else if is_next_race(&race_id) {
                 let btn =
                     Button::new("Start Race").on_click(move |ctx, data: &mut AppState, _env| {
                         start_race(race_id);
+                        data.playback = Some(RacePlayback {
+                            race_id,
+                            lap: 0,
+                            lap_amount: race_distance(&get_race_results(&race_id, "Race")),
+                            playing: true,
+                            speed: 1,
+                        });
                         data.last_race_update_time = Utc::now().to_string();
                         data.current_screen = RaceScreen { race_id };
                         ctx.request_update();
                     });
-                Box::new(btn)
+                let strategy_btn =
+                    Button::new("Plan Strategy").on_click(move |ctx, data: &mut AppState, _env| {
+                        data.current_screen = StrategyScreen { race_id };
+                        ctx.request_update();
+                    });
+                let weekend_format = if race.is_sprint {
+                    "Sprint weekend"
+                } else {
+                    "Standard weekend"
+                };
+                Box::new(
+                    Flex::column()
+                        .with_child(Label::new(weekend_format))
+                        .with_child(Label::new(format!(
+                            "Forecast: {}% chance of rain",
+                            race_forecast(race_id)
+                        )))
+                        .with_spacer(10.0)
+                        .with_child(
+                            Flex::row()
+                                .with_child(practice_button(race_id))
+                                .with_spacer(10.0)
+                                .with_child(strategy_btn)
+                                .with_spacer(10.0)
+                                .with_child(btn),
+                        )
+                        .with_spacer(10.0)
+                        .with_child(build_prediction(race_id)),
+                )
             } else {
                 Box::new(Label::new("This isn't the next race."))
             }
@@ -92,7 +238,10 @@ pub fn build_screen(race_id: u16) -> impl Widget<AppState> {
     let column1 = Flex::column()
         .cross_axis_alignment(druid::widget::CrossAxisAlignment::Start)
         .with_spacer(10.0)
-        .with_child(results_switcher);
+        .with_child(results_switcher.controller(PlaybackController {
+            race_id,
+            timer: TimerToken::INVALID,
+        }));
 
     let mut column2 = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
     column2.add_child(circuit_image);
@@ -112,3 +261,519 @@ pub fn build_screen(race_id: u16) -> impl Widget<AppState> {
             1.0,
         )
 }
+
+// Replays the main race lap by lap from the saved laps, skipping to the end shows the full results.
+// While it is paused the player can give orders to their drivers
+fn build_playback(playback: &RacePlayback, selected_team: Option<&str>) -> impl Widget<AppState> {
+    let results = get_race_results(&playback.race_id, "Race");
+    let safety_car_periods = get_safety_car_periods(&playback.race_id, "Race");
+    let standings = standings_after_lap(&results, playback.lap);
+    let own_drivers: Vec<String> = standings
+        .iter()
+        .filter(|s| !s.retired && Some(s.team.as_str()) == selected_team)
+        .map(|s| s.driver_name.clone())
+        .collect();
+    let format_gap = |gap_ms: Option<u32>| gap_ms.map_or("-".to_string(), format_gap);
+    let rows: Vec<Vec<String>> = standings
+        .into_iter()
+        .map(|s| {
+            vec![
+                s.position.to_string(),
+                s.driver_name,
+                s.team,
+                if s.retired {
+                    "OUT".to_string()
+                } else if s.laps_down > 0 {
+                    format_laps_down(s.laps_down as usize)
+                } else {
+                    format_gap(s.gap_ms)
+                },
+                format_gap(s.interval_ms),
+                Compound::from_name(&s.compound)
+                    .map_or("-", |c| c.short_name())
+                    .to_string(),
+                s.pit_stops.to_string(),
+            ]
+        })
+        .collect();
+
+    let play_label = if playback.playing { "Pause" } else { "Play" };
+    let play_btn = Button::new(play_label).on_click(|_ctx, data: &mut AppState, _env| {
+        if let Some(playback) = data.playback.as_mut() {
+            // playing again from the chequered flag restarts the replay
+            if !playback.playing && playback.lap >= playback.lap_amount {
+                playback.lap = 0;
+            }
+            playback.playing = !playback.playing;
+        }
+    });
+    let speed_btn = Button::new(format!("Speed: {}x", playback.speed)).on_click(
+        |_ctx, data: &mut AppState, _env| {
+            if let Some(playback) = data.playback.as_mut() {
+                playback.speed = if playback.speed >= MAX_PLAYBACK_SPEED {
+                    1
+                } else {
+                    playback.speed * 2
+                };
+            }
+        },
+    );
+    // the full results leave no more room for orders, so the race is final
+    let skip_btn = Button::new("Skip to End").on_click(|_ctx, data: &mut AppState, _env| {
+        data.playback = None;
+        finalise_races();
+    });
+
+    let mut events = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+    for event in events_up_to_lap(&results, &safety_car_periods, playback.lap)
+        .into_iter()
+        .take(PLAYBACK_EVENT_COUNT)
+    {
+        events.add_child(Label::new(format!(
+            "Lap {}: {}",
+            event.lap, event.description
+        )));
+    }
+
+    let mut orders = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+    if !playback.playing && playback.lap < playback.lap_amount && !own_drivers.is_empty() {
+        orders.add_child(Label::new(format!(
+            "Team Orders for lap {}",
+            playback.lap + 1
+        )));
+        for driver_name in own_drivers {
+            orders.add_spacer(5.0);
+            orders.add_child(build_driver_orders(
+                playback.race_id,
+                playback.lap + 1,
+                &driver_name,
+            ));
+        }
+        for decision in get_race_decisions(&playback.race_id) {
+            let Some(driver) = get_driver_by_id(&decision.driver_id) else {
+                continue;
+            };
+            orders.add_child(Label::new(format!(
+                "Lap {}: {} {} - {}{}",
+                decision.lap,
+                driver.first_name,
+                driver.last_name,
+                decision.decision,
+                decision
+                    .compound
+                    .map_or(String::new(), |c| format!(" ({})", c))
+            )));
+        }
+        orders.add_spacer(10.0);
+    }
+
+    Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(Label::new(format!(
+            "Lap {}/{}",
+            playback.lap, playback.lap_amount
+        )))
+        .with_spacer(10.0)
+        .with_child(
+            Flex::row()
+                .with_child(play_btn)
+                .with_spacer(10.0)
+                .with_child(speed_btn)
+                .with_spacer(10.0)
+                .with_child(skip_btn),
+        )
+        .with_spacer(10.0)
+        .with_child(orders)
+        .with_child(
+            SizedBox::new(
+                Scroll::new(make_table(
+                    vec![
+                        "Position".into(),
+                        "DriverName".into(),
+                        "Team".into(),
+                        "Gap".into(),
+                        "Interval".into(),
+                        "Tyre".into(),
+                        "Stops".into(),
+                    ],
+                    rows,
+                    vec![(1, goto_driver()), (2, goto_team())],
+                ))
+                .vertical(),
+            )
+            .height(400.0),
+        )
+        .with_spacer(20.0)
+        .with_child(Label::new("Race Events"))
+        .with_spacer(10.0)
+        .with_child(events)
+}
+
+// Orders for one of the player's drivers, each one simulates the rest of the race again
+fn build_driver_orders(race_id: u16, lap: u8, driver_name: &str) -> impl Widget<AppState> {
+    let driver_id = get_driver_id_by_fullname(driver_name).unwrap();
+    let order_btn = move |label: String, order: RaceOrder| {
+        Button::new(label).on_click(move |_ctx, data: &mut AppState, _env| {
+            give_race_order(
+                race_id,
+                DriverOrder {
+                    driver_id,
+                    lap,
+                    order,
+                },
+            );
+            data.last_race_update_time = Utc::now().to_string();
+        })
+    };
+
+    let mut pit_row = Flex::row().with_child(Label::new("Box for:"));
+    for compound in [
+        Compound::Soft,
+        Compound::Medium,
+        Compound::Hard,
+        Compound::Intermediate,
+        Compound::Wet,
+    ] {
+        pit_row.add_spacer(5.0);
+        pit_row.add_child(order_btn(
+            compound.name().to_string(),
+            RaceOrder::PitStop(compound),
+        ));
+    }
+    let mut mode_row = Flex::row().with_child(Label::new("Tyres:"));
+    for mode in [TyreMode::Push, TyreMode::Normal, TyreMode::Conserve] {
+        mode_row.add_spacer(5.0);
+        mode_row.add_child(order_btn(
+            mode.name().to_string(),
+            RaceOrder::TyreMode(mode),
+        ));
+    }
+    mode_row.add_spacer(15.0);
+    mode_row.add_child(order_btn(
+        "Let Teammate Pass".to_string(),
+        RaceOrder::LetTeammatePass,
+    ));
+
+    Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(Label::new(driver_name.to_string()))
+        .with_child(pit_row)
+        .with_child(mode_row)
+}
+
+// Predicted chances for the upcoming race, only simulated once the player asks for it and in the
+// background, the screen keeps working in the meantime
+fn build_prediction(race_id: u16) -> impl Widget<AppState> {
+    ViewSwitcher::new(
+        |data: &AppState, _env: &Env| (data.prediction_race_id, data.prediction.clone()),
+        move |(prediction_race_id, prediction), _data: &AppState, _env| {
+            if *prediction_race_id != Some(race_id) {
+                return Box::new(Button::new("Predict Outcome").on_click(
+                    move |ctx, data: &mut AppState, _env| {
+                        data.prediction_race_id = Some(race_id);
+                        data.prediction = None;
+                        let sink = ctx.get_external_handle();
+                        thread::spawn(move || {
+                            let prediction = Arc::new(predict_race(race_id));
+                            sink.submit_command(
+                                PREDICTION_READY,
+                                (race_id, prediction),
+                                Target::Auto,
+                            )
+                        });
+                        ctx.request_update();
+                    },
+                ));
+            }
+            let Some(prediction) = prediction
+                .as_ref()
+                .filter(|(id, _)| *id == race_id)
+                .map(|(_, prediction)| prediction)
+            else {
+                return Box::new(Label::new("Simulating the race..."));
+            };
+
+            let team_name = |team_id: &u16| get_team_info(team_id).unwrap().short_name;
+            let driver_rows: Vec<Vec<String>> = prediction
+                .drivers
+                .iter()
+                .map(|p| {
+                    let driver = get_driver_by_id(&p.driver_id).unwrap();
+                    vec![
+                        format!("{} {}", driver.first_name, driver.last_name),
+                        team_name(&p.team_id),
+                        format_chance(p.win_chance),
+                        format_chance(p.podium_chance),
+                        format_chance(p.points_chance),
+                        format!("{:.1}", p.expected_points),
+                    ]
+                })
+                .collect();
+            let team_rows: Vec<Vec<String>> = prediction
+                .teams
+                .iter()
+                .map(|p| {
+                    vec![
+                        team_name(&p.team_id),
+                        format_chance(p.win_chance),
+                        format_chance(p.podium_chance),
+                        format_chance(p.points_chance),
+                        format!("{:.1}", p.expected_points),
+                    ]
+                })
+                .collect();
+
+            Box::new(
+                Flex::column()
+                    .with_child(Label::new("Predicted Drivers"))
+                    .with_spacer(10.0)
+                    .with_child(
+                        SizedBox::new(
+                            Scroll::new(make_table(
+                                vec![
+                                    "DriverName".into(),
+                                    "Team".into(),
+                                    "Win".into(),
+                                    "Podium".into(),
+                                    "Points".into(),
+                                    "Exp. Points".into(),
+                                ],
+                                driver_rows,
+                                vec![(0, goto_driver()), (1, goto_team())],
+                            ))
+                            .vertical(),
+                        )
+                        .height(300.0),
+                    )
+                    .with_spacer(20.0)
+                    .with_child(Label::new("Predicted Teams"))
+                    .with_spacer(10.0)
+                    .with_child(
+                        SizedBox::new(
+                            Scroll::new(make_table(
+                                vec![
+                                    "Team".into(),
+                                    "Win".into(),
+                                    "Podium".into(),
+                                    "Points".into(),
+                                    "Exp. Points".into(),
+                                ],
+                                team_rows,
+                                vec![(0, goto_team())],
+                            ))
+                            .vertical(),
+                        )
+                        .height(200.0),
+                    ),
+            )
+        },
+    )
+}
+
+fn format_chance(chance: f32) -> String {
+    format!("{:.1}%", chance * 100.0)
+}
+
+// Results of one race of the weekend followed by the qualifying that set its grid
+fn build_session_results(race_id: u16, session: &str, table_height: f64) -> Flex<AppState> {
+    let results = get_race_results(&race_id, session);
+    let (leader_laps, leader_ms) = results
+        .first()
+        .map_or((0, 0), |r| (r.laps.len(), r.total_time_ms));
+    let rows: Vec<Vec<String>> = results
+        .into_iter()
+        .map(|r| {
+            // the winner shows the race time, the cars behind the gap or the laps they are down
+            let laps_down = leader_laps.saturating_sub(r.laps.len());
+            vec![
+                r.position.to_string(),
+                r.grid_position.map_or("-".to_string(), |g| g.to_string()),
+                r.grid_position.map_or("-".to_string(), |g| {
+                    format!("{:+}", g as i16 - r.position as i16)
+                }),
+                r.driver_number.to_string(),
+                r.driver_name,
+                r.team,
+                r.points.to_string(),
+                match (r.status.as_str(), r.retired_lap, r.retirement_reason) {
+                    ("DSQ" | "NC", _, _) => r.status,
+                    ("Classified", _, Some(reason)) => {
+                        format!("{} - {}", format_laps_down(laps_down), reason)
+                    }
+                    (_, Some(lap), Some(reason)) => {
+                        format!("{} - {} (lap {})", r.status, reason, lap)
+                    }
+                    _ if r.position == 1 => format_time(r.total_time_ms),
+                    _ if laps_down > 0 => format_laps_down(laps_down),
+                    _ => format_gap(r.total_time_ms.saturating_sub(leader_ms)),
+                },
+                match r.penalty_seconds {
+                    0 => "-".to_string(),
+                    seconds => format!("+{} s", seconds),
+                },
+                r.stints.len().saturating_sub(1).to_string(),
+                r.stints
+                    .iter()
+                    .map(|stint| {
+                        format!(
+                            "{} {}-{}",
+                            Compound::from_name(&stint.compound).map_or("?", |c| c.short_name()),
+                            stint.start_lap,
+                            stint.end_lap
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ]
+        })
+        .collect();
+    let qualifying_rows: Vec<Vec<String>> = get_qualifying_results(&race_id, session)
+        .into_iter()
+        .map(|r| {
+            vec![
+                r.position.to_string(),
+                r.driver_number.to_string(),
+                r.driver_name,
+                r.team,
+                format_lap_time(r.q1_time_ms),
+                r.q2_time_ms.map_or("-".to_string(), format_lap_time),
+                r.q3_time_ms.map_or("-".to_string(), format_lap_time),
+            ]
+        })
+        .collect();
+    let qualifying_name = match session {
+        "Sprint" => "Sprint Qualifying",
+        _ => "Qualifying",
+    };
+
+    Flex::column()
+        .with_child(Label::new(format!("{} Results", session)))
+        .with_child(Label::new(format!(
+            "Conditions: {}",
+            format_conditions(&get_race_weather(&race_id, session))
+        )))
+        .with_child(Label::new(format!(
+            "Neutralised: {}",
+            format_safety_car_periods(&race_id, session)
+        )))
+        .with_child(Label::new(format!(
+            "Penalties: {}",
+            format_penalties(&race_id, session)
+        )))
+        .with_spacer(10.0)
+        .with_child(
+            SizedBox::new(
+                Scroll::new(make_table(
+                    vec![
+                        "Position".into(),
+                        "Grid".into(),
+                        "+/-".into(),
+                        "DriverNumber".into(),
+                        "DriverName".into(),
+                        "Team".into(),
+                        "Points".into(),
+                        "Time/Gap".into(),
+                        "Penalty".into(),
+                        "Stops".into(),
+                        "Stints".into(),
+                    ],
+                    rows,
+                    vec![(4, goto_driver()), (5, goto_team())],
+                ))
+                .vertical(),
+            )
+            .height(table_height),
+        )
+        .with_spacer(20.0)
+        .with_child(Label::new(format!("{} Results", qualifying_name)))
+        .with_spacer(10.0)
+        .with_child(
+            SizedBox::new(
+                Scroll::new(make_table(
+                    vec![
+                        "Position".into(),
+                        "DriverNumber".into(),
+                        "DriverName".into(),
+                        "Team".into(),
+                        "Q1".into(),
+                        "Q2".into(),
+                        "Q3".into(),
+                    ],
+                    qualifying_rows,
+                    vec![(2, goto_driver()), (3, goto_team())],
+                ))
+                .vertical(),
+            )
+            .height(table_height),
+        )
+}
+
+fn practice_button(race_id: u16) -> impl Widget<AppState> {
+    Button::new("Practice").on_click(move |ctx, data: &mut AppState, _env| {
+        data.current_screen = PracticeScreen { race_id };
+        ctx.request_update();
+    })
+}
+
+// Groups the lap conditions into runs, for example "Dry 1-12, Wet 13-30, Dry 31-58"
+fn format_conditions(conditions: &[String]) -> String {
+    if conditions.is_empty() {
+        return "-".to_string();
+    }
+    let mut runs: Vec<(String, usize, usize)> = Vec::new();
+    for (index, condition) in conditions.iter().enumerate() {
+        match runs.last_mut() {
+            Some((last, _, end)) if last == condition => *end = index + 1,
+            _ => runs.push((condition.clone(), index + 1, index + 1)),
+        }
+    }
+    runs.iter()
+        .map(|(condition, start, end)| format!("{} {}-{}", condition, start, end))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+// Lists the stewards' decisions, for example "Max Verstappen, Time Penalty 5 s - Track limits
+// (lap 23)". The race also lists the grid penalties it served
+fn format_penalties(race_id: &u16, session: &str) -> String {
+    let mut penalties = get_penalties(race_id, session);
+    if session == "Race" {
+        penalties.extend(grid_penalties(*race_id).into_iter().map(|mut p| {
+            p.reason = format!("{}, served on this grid", p.reason);
+            p.lap = None;
+            p
+        }));
+    }
+    if penalties.is_empty() {
+        return "-".to_string();
+    }
+    penalties
+        .iter()
+        .map(|p| {
+            let driver_name = get_driver_by_id(&p.driver_id).map_or("?".to_string(), |d| {
+                format!("{} {}", d.first_name, d.last_name)
+            });
+            let value = match p.kind.as_str() {
+                "Time Penalty" => format!(" {} s", p.value),
+                "Grid Penalty" => format!(" {} places", p.value),
+                _ => String::new(),
+            };
+            let lap = p.lap.map_or(String::new(), |lap| format!(" (lap {})", lap));
+            format!("{}, {}{} - {}{}", driver_name, p.kind, value, p.reason, lap)
+        })
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
+// Lists the safety car and virtual safety car periods, for example "Safety Car 12-15 (Collision)"
+fn format_safety_car_periods(race_id: &u16, session: &str) -> String {
+    let periods = get_safety_car_periods(race_id, session);
+    if periods.is_empty() {
+        return "-".to_string();
+    }
+    periods
+        .iter()
+        .map(|p| format!("{} {}-{} ({})", p.kind, p.start_lap, p.end_lap, p.reason))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
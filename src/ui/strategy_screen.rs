@@ -0,0 +1,272 @@
+use super::{AppState, StrategyEstimate, STRATEGY_ESTIMATE_READY};
+use crate::backend::strategy::{
+    add_stop, default_race_strategy, move_stop, remove_stop, strategy_delta, EngineMode, FuelMode,
+};
+use crate::backend::tyre::Compound;
+use crate::database::circuit::get_circuit_by_id;
+use crate::database::driver::{get_all_drivers, get_team_id_by_driver_id};
+use crate::database::race::get_season_schedule_by_id;
+use crate::database::strategy::{delete_race_strategy, get_race_strategies, save_race_strategy};
+use crate::database::teams::get_team_id_by_short_name;
+use crate::model::{Driver, RaceStrategy};
+use crate::ui::Screen::RaceScreen;
+use crate::ui::ViewSwitcher;
+use chrono::Utc;
+use druid::widget::{Button, CrossAxisAlignment, Flex, Label};
+use druid::{Env, Target, Widget, WidgetExt};
+use std::sync::Arc;
+use std::thread;
+
+// Compounds a plan can start on or switch to, the wet weather tyres are fitted when it rains
+const DRY_COMPOUNDS: [Compound; 3] = [Compound::Soft, Compound::Medium, Compound::Hard];
+
+pub fn build_screen(race_id: u16) -> impl Widget<AppState> {
+    let race = get_season_schedule_by_id(&race_id).unwrap();
+    let circuit = get_circuit_by_id(&race.circuit_id).unwrap();
+    let lap_amount = circuit.lap_amount;
+    let back_btn = Button::new("Back to Race").on_click(move |ctx, data: &mut AppState, _env| {
+        data.current_screen = RaceScreen { race_id };
+        ctx.request_update();
+    });
+
+    let plans_switcher = ViewSwitcher::new(
+        |data: &AppState, _env: &Env| {
+            (
+                data.last_race_update_time.clone(),
+                data.selected_team.clone(),
+                data.strategy_estimates.clone(),
+            )
+        },
+        move |(_, selected_team, estimates), _data: &AppState, _env| {
+            let team_id = selected_team.as_deref().and_then(get_team_id_by_short_name);
+            let strategies = get_race_strategies(&race_id);
+
+            let mut plans = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+            for driver in get_all_drivers()
+                .into_iter()
+                .filter(|d| team_id.is_some() && get_team_id_by_driver_id(&d.id) == team_id)
+            {
+                let strategy = strategies.iter().find(|s| s.driver_id == driver.id);
+                plans.add_child(build_driver_plan(
+                    race_id, lap_amount, &driver, strategy, estimates,
+                ));
+                plans.add_spacer(20.0);
+            }
+            Box::new(plans)
+        },
+    );
+
+    Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(Label::new(format!("Race Strategy: {}", circuit.name)).with_text_size(20.0))
+        .with_spacer(10.0)
+        .with_child(back_btn)
+        .with_spacer(20.0)
+        .with_child(plans_switcher)
+        .padding(10.0)
+}
+
+// Every change to a plan is saved straight away and the screen is rebuilt
+fn update_plan(
+    race_id: u16,
+    lap_amount: u8,
+    driver_id: u16,
+    change: impl Fn(&mut RaceStrategy) + 'static,
+) -> impl Fn(&mut druid::EventCtx, &mut AppState, &Env) {
+    move |_ctx, data: &mut AppState, _env| {
+        let mut strategy = get_race_strategies(&race_id)
+            .into_iter()
+            .find(|s| s.driver_id == driver_id)
+            .unwrap_or_else(|| default_race_strategy(driver_id, lap_amount));
+        change(&mut strategy);
+        save_race_strategy(race_id, &strategy);
+        data.last_race_update_time = Utc::now().to_string();
+    }
+}
+
+fn build_driver_plan(
+    race_id: u16,
+    lap_amount: u8,
+    driver: &Driver,
+    strategy: Option<&RaceStrategy>,
+    estimates: &[StrategyEstimate],
+) -> impl Widget<AppState> {
+    let driver_id = driver.id;
+    let mut column = Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(
+            Label::new(format!("{} {}", driver.first_name, driver.last_name)).with_text_size(16.0),
+        );
+
+    let Some(strategy) = strategy else {
+        column.add_child(Label::new("Strategy: picked by the team"));
+        column.add_child(Button::new("Plan Strategy").on_click(update_plan(
+            race_id,
+            lap_amount,
+            driver_id,
+            |_| {},
+        )));
+        return column;
+    };
+
+    for (index, stint) in strategy.stints.iter().enumerate() {
+        let compound_btn = Button::new(stint.compound.clone()).on_click(update_plan(
+            race_id,
+            lap_amount,
+            driver_id,
+            move |s| {
+                let current = Compound::from_name(&s.stints[index].compound);
+                let position = DRY_COMPOUNDS.iter().position(|c| Some(*c) == current);
+                let next = DRY_COMPOUNDS[position.map_or(0, |p| (p + 1) % DRY_COMPOUNDS.len())];
+                s.stints[index].compound = next.name().to_string();
+            },
+        ));
+        let start_lap = match index {
+            0 => 1,
+            _ => strategy.stints[index - 1].end_lap + 1,
+        };
+        let mut row = Flex::row()
+            .with_child(Label::new(format!(
+                "Stint {}: laps {}-{} on",
+                index + 1,
+                start_lap,
+                stint.end_lap
+            )))
+            .with_spacer(5.0)
+            .with_child(compound_btn);
+        if index + 1 < strategy.stints.len() {
+            row.add_spacer(10.0);
+            row.add_child(Label::new(format!("Stop on lap {}", stint.end_lap)));
+            for laps in [-5, -1, 1, 5] {
+                row.add_spacer(5.0);
+                row.add_child(Button::new(format!("{:+}", laps)).on_click(update_plan(
+                    race_id,
+                    lap_amount,
+                    driver_id,
+                    move |s| move_stop(s, index, laps),
+                )));
+            }
+            row.add_spacer(5.0);
+            row.add_child(Button::new("Remove Stop").on_click(update_plan(
+                race_id,
+                lap_amount,
+                driver_id,
+                move |s| remove_stop(s, index),
+            )));
+        }
+        column.add_child(row);
+    }
+    column.add_child(
+        Button::new("Add Stop").on_click(update_plan(race_id, lap_amount, driver_id, add_stop)),
+    );
+
+    let fuel_btn = Button::new(format!("Fuel: {}", strategy.fuel_mode)).on_click(update_plan(
+        race_id,
+        lap_amount,
+        driver_id,
+        |s| {
+            let mode = FuelMode::from_name(&s.fuel_mode).unwrap_or(FuelMode::Standard);
+            s.fuel_mode = mode.next().name().to_string();
+        },
+    ));
+    let engine_btn = Button::new(format!("Engine: {}", strategy.engine_mode)).on_click(
+        update_plan(race_id, lap_amount, driver_id, |s| {
+            let mode = EngineMode::from_name(&s.engine_mode).unwrap_or(EngineMode::Standard);
+            s.engine_mode = mode.next().name().to_string();
+        }),
+    );
+    column.add_spacer(5.0);
+    column.add_child(
+        Flex::row()
+            .with_child(fuel_btn)
+            .with_spacer(10.0)
+            .with_child(engine_btn),
+    );
+
+    let dry_compounds = strategy
+        .stints
+        .iter()
+        .map(|s| s.compound.as_str())
+        .collect::<std::collections::HashSet<&str>>()
+        .len();
+    if dry_compounds < 2 {
+        column.add_child(Label::new(
+            "The rules require two dry compounds in a dry race, or the driver is disqualified",
+        ));
+    }
+    column.add_child(build_estimate(race_id, strategy, estimates));
+    column.add_child(Button::new("Use Team Strategy").on_click(
+        move |_ctx, data: &mut AppState, _env| {
+            delete_race_strategy(race_id, driver_id);
+            data.last_race_update_time = Utc::now().to_string();
+        },
+    ));
+    column
+}
+
+// Identifies a version of the plan, an estimate is only shown for the plan it was made for
+fn plan_key(strategy: &RaceStrategy) -> String {
+    let stints: Vec<String> = strategy
+        .stints
+        .iter()
+        .map(|s| format!("{}-{}", s.compound, s.end_lap))
+        .collect();
+    format!(
+        "{} {} {}",
+        stints.join(","),
+        strategy.fuel_mode,
+        strategy.engine_mode
+    )
+}
+
+// The estimate simulates the race many times, so it only runs when the player asks for it and
+// in the background, the screen keeps working in the meantime
+fn build_estimate(
+    race_id: u16,
+    strategy: &RaceStrategy,
+    estimates: &[StrategyEstimate],
+) -> Box<dyn Widget<AppState>> {
+    let driver_id = strategy.driver_id;
+    let plan = plan_key(strategy);
+    let estimate = estimates
+        .iter()
+        .find(|e| e.race_id == race_id && e.driver_id == driver_id && e.plan == plan);
+    match estimate {
+        Some(estimate) if estimate.ready => Box::new(Label::new(match estimate.delta {
+            Some(delta) => format!(
+                "Estimated race time: {:+.1} s against the team's strategy",
+                delta
+            ),
+            None => "Estimated race time: -".to_string(),
+        })),
+        Some(_) => Box::new(Label::new("Estimated race time: simulating...")),
+        None => {
+            let strategy = strategy.clone();
+            Box::new(Button::new("Estimate Race Time").on_click(
+                move |ctx, data: &mut AppState, _env| {
+                    let pending = StrategyEstimate {
+                        race_id,
+                        driver_id,
+                        plan: plan.clone(),
+                        delta: None,
+                        ready: false,
+                    };
+                    let mut estimates = data.strategy_estimates.as_ref().clone();
+                    estimates.push(pending.clone());
+                    data.strategy_estimates = Arc::new(estimates);
+
+                    let sink = ctx.get_external_handle();
+                    let strategy = strategy.clone();
+                    thread::spawn(move || {
+                        let estimate = StrategyEstimate {
+                            delta: strategy_delta(race_id, &strategy),
+                            ready: true,
+                            ..pending
+                        };
+                        sink.submit_command(STRATEGY_ESTIMATE_READY, estimate, Target::Auto)
+                    });
+                },
+            ))
+        }
+    }
+}
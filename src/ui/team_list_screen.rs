@@ -1,4 +1,5 @@
 use super::AppState;
+use crate::database::season::get_current_season;
 use crate::database::teams::get_team_data;
 use crate::ui::component::goto::{goto_driver, goto_team, goto_team_fullname};
 use crate::ui::component::table::make_table;
@@ -6,6 +7,7 @@ use druid::widget::{Flex, Scroll, SizedBox};
 use druid::Widget;
 
 pub fn build_screen() -> impl Widget<AppState> {
+    let season = get_current_season().unwrap();
     Flex::column().with_spacer(20.0).with_child(
         SizedBox::new(
             Scroll::new(make_table(
@@ -16,7 +18,7 @@ pub fn build_screen() -> impl Widget<AppState> {
                     "Driver 1".to_string(),
                     "Driver 2".to_string(),
                 ],
-                get_team_data(),
+                get_team_data(&season.id),
                 vec![
                     (0, goto_team()),
                     (1, goto_team_fullname()),
@@ -1,16 +1,28 @@
 use super::AppState;
-use crate::database::teams::{get_team_base_by_team_id, get_team_info, get_team_season_info};
+use crate::backend::power_unit::{fit_new_component, fitted_components, ComponentKind};
+use crate::database::driver::{get_all_drivers, get_team_id_by_driver_id};
+use crate::database::power_unit::get_power_unit_components;
+use crate::database::race::get_next_race;
+use crate::database::season::get_current_season;
+use crate::database::teams::{
+    get_car_performance, get_team_base_by_team_id, get_team_id_by_short_name, get_team_info,
+    get_team_season_info,
+};
 use crate::model::RaceInfo;
 use crate::ui::component::goto::goto_race;
 use crate::ui::component::table::make_table;
+use crate::ui::ViewSwitcher;
 use crate::util::image_loader::{get_car, get_team};
-use druid::widget::{CrossAxisAlignment, Flex, Label, MainAxisAlignment, Scroll, SizedBox};
-use druid::Widget;
+use chrono::Utc;
+use druid::widget::{Button, CrossAxisAlignment, Flex, Label, MainAxisAlignment, Scroll, SizedBox};
+use druid::{Env, Widget};
 
 pub fn build_screen(team_id: &u16) -> impl Widget<AppState> {
     let team_info = get_team_info(&team_id).unwrap();
     let team_base = get_team_base_by_team_id(&team_id).unwrap();
-    let season_info = get_team_season_info(&team_info.id, &2025).unwrap();
+    let car = get_car_performance(team_id).unwrap();
+    let season = get_current_season().unwrap();
+    let season_info = get_team_season_info(&team_info.id, &season.year).unwrap();
 
     let mut left_column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
     left_column.add_child(Label::new("Season Info:").with_text_size(20.0));
@@ -56,12 +68,18 @@ pub fn build_screen(team_id: &u16) -> impl Widget<AppState> {
                     "Points".to_string(),
                 ],
                 team_results_data,
-                vec![(0, goto_race())],
+                vec![(0, goto_race(season.id))],
             ))
             .vertical(),
         )
         .height(400.0),
     );
+    left_column.add_spacer(10.0);
+    left_column.add_child(Label::new("Power Units:").with_text_size(20.0));
+    left_column.add_spacer(5.0);
+    left_column.add_child(
+        SizedBox::new(Scroll::new(build_power_units(*team_id)).vertical()).height(300.0),
+    );
 
     let mut right_column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
     right_column.add_child(get_team(&team_info.image_path_logo));
@@ -78,6 +96,16 @@ pub fn build_screen(team_id: &u16) -> impl Widget<AppState> {
         "First Entry:\t{}",
         team_info.first_entry
     )));
+    right_column.add_spacer(10.0);
+    right_column.add_child(Label::new("Car Performance:"));
+    right_column.add_spacer(5.0);
+    right_column.add_child(Label::new(format!("Aero:\t\t{}", car.aero)));
+    right_column.add_child(Label::new(format!("Power:\t\t{}", car.power)));
+    right_column.add_child(Label::new(format!(
+        "Mechanical Grip:\t{}",
+        car.mechanical_grip
+    )));
+    right_column.add_child(Label::new(format!("Reliability:\t{}", car.reliability)));
 
     let layout = Flex::row()
         .main_axis_alignment(MainAxisAlignment::Center)
@@ -93,3 +121,80 @@ pub fn build_screen(team_id: &u16) -> impl Widget<AppState> {
         .with_spacer(20.0)
         .with_child(layout)
 }
+
+// The power unit components in each driver's car, the player can fit new ones to their own cars
+fn build_power_units(team_id: u16) -> impl Widget<AppState> {
+    ViewSwitcher::new(
+        |data: &AppState, _env: &Env| {
+            (
+                data.last_race_update_time.clone(),
+                data.selected_team.clone(),
+            )
+        },
+        move |(_, selected_team), _data: &AppState, _env| {
+            let Some(next_race) = get_next_race() else {
+                return Box::new(Label::new("The season is over"));
+            };
+            let season_id = next_race.season_id;
+            let is_player_team =
+                selected_team.as_deref().and_then(get_team_id_by_short_name) == Some(team_id);
+
+            let mut column = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+            for driver in get_all_drivers()
+                .into_iter()
+                .filter(|d| get_team_id_by_driver_id(&d.id) == Some(team_id))
+            {
+                let driver_id = driver.id;
+                let fitted = fitted_components(season_id, driver_id, next_race.id);
+                let all = get_power_unit_components(&season_id, &driver_id);
+                let rows: Vec<Vec<String>> = ComponentKind::ALL
+                    .iter()
+                    .map(|kind| {
+                        let used = all.iter().filter(|c| c.kind == kind.name()).count();
+                        let component = fitted.iter().find(|c| c.kind == kind.name());
+                        vec![
+                            kind.name().to_string(),
+                            format!("{} of {}", used, kind.allocation()),
+                            component
+                                .map_or("-".to_string(), |c| format!("{:.0}%", c.wear() * 100.0)),
+                            all.iter()
+                                .filter(|c| c.kind == kind.name() && c.failed)
+                                .count()
+                                .to_string(),
+                        ]
+                    })
+                    .collect();
+
+                column.add_child(Label::new(format!(
+                    "{} {}",
+                    driver.first_name, driver.last_name
+                )));
+                column.add_child(make_table(
+                    vec![
+                        "Component".to_string(),
+                        "Used".to_string(),
+                        "Wear".to_string(),
+                        "Failed".to_string(),
+                    ],
+                    rows,
+                    vec![],
+                ));
+                if is_player_team {
+                    let mut buttons = Flex::row();
+                    for kind in ComponentKind::ALL {
+                        buttons.add_child(Button::new(format!("New {}", kind.name())).on_click(
+                            move |_ctx, data: &mut AppState, _env| {
+                                fit_new_component(season_id, driver_id, kind);
+                                data.last_race_update_time = Utc::now().to_string();
+                            },
+                        ));
+                        buttons.add_spacer(5.0);
+                    }
+                    column.add_child(buttons);
+                }
+                column.add_spacer(10.0);
+            }
+            Box::new(column)
+        },
+    )
+}
@@ -10,3 +10,23 @@ pub fn format_time(ms: u32) -> String {
         hours, minutes, seconds, milliseconds
     )
 }
+
+pub fn format_lap_time(ms: u32) -> String {
+    let seconds = ms / 1000;
+    let milliseconds = ms % 1000;
+    let minutes = seconds / 60;
+    let seconds = seconds % 60;
+    format!("{}:{:02}.{:03}", minutes, seconds, milliseconds)
+}
+
+pub fn format_gap(ms: u32) -> String {
+    format!("+{}.{:03}", ms / 1000, ms % 1000)
+}
+
+pub fn format_laps_down(laps: usize) -> String {
+    if laps == 1 {
+        "+1 Lap".to_string()
+    } else {
+        format!("+{} Laps", laps)
+    }
+}